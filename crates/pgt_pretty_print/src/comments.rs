@@ -0,0 +1,199 @@
+//! Comment preservation.
+//!
+//! `pgt_query`'s parse tree has no room for comments, so every `-- ...` and
+//! `/* ... */` in the input would otherwise be silently dropped on format.
+//! This module re-scans the original SQL with `pgt_query`'s raw scanner to
+//! recover comment tokens, then binds each one to the AST node whose
+//! `location` byte offset it sits closest to - the same span-tracking trick
+//! `sqlparser-rs` uses to attach source positions, repurposed here to carry
+//! comments through a lossy AST.
+//!
+//! Node emitters don't call this module directly: the top-level format
+//! entry point scans once per statement, attaches the result, and hands
+//! each node emitter its leading/trailing comments to flush through
+//! [`crate::emitter::EventEmitter::comment`].
+
+use std::cell::RefCell;
+use std::collections::HashMap;
+use std::rc::Rc;
+
+use crate::emitter::{Comment, CommentPosition, EmitterHook, EventEmitter};
+
+/// A comment recovered from the raw token stream.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct SourceComment {
+    /// Full comment text, including the `--` marker or `/* */` delimiters.
+    pub text: String,
+    pub start: usize,
+    pub end: usize,
+    pub is_block: bool,
+}
+
+/// Comments attached to AST nodes, keyed by each node's `location` field.
+#[derive(Debug, Default)]
+pub struct CommentMap {
+    leading: HashMap<i32, Vec<SourceComment>>,
+    trailing: HashMap<i32, Vec<SourceComment>>,
+    /// Comments that attach to nothing - those with no node location on
+    /// either side, e.g. in a statement whose emission records no node
+    /// locations at all. Callers fall back to hanging these off the enclosing
+    /// statement.
+    orphaned: Vec<SourceComment>,
+}
+
+impl CommentMap {
+    /// Take (and remove) the comments that should be printed on their own
+    /// line immediately before the node at `location`.
+    pub fn take_leading(&mut self, location: i32) -> Vec<SourceComment> {
+        self.leading.remove(&location).unwrap_or_default()
+    }
+
+    /// Take (and remove) the comments that should be printed on the same
+    /// line immediately after the node at `location`.
+    pub fn take_trailing(&mut self, location: i32) -> Vec<SourceComment> {
+        self.trailing.remove(&location).unwrap_or_default()
+    }
+
+    pub fn orphaned(&self) -> &[SourceComment] {
+        &self.orphaned
+    }
+}
+
+/// Re-scan `sql` and collect every comment token in source order.
+pub fn scan_comments(sql: &str) -> Vec<SourceComment> {
+    let Ok(scan_result) = pgt_query::scan(sql) else {
+        return Vec::new();
+    };
+
+    scan_result
+        .tokens
+        .into_iter()
+        .filter_map(|token| {
+            let is_block = match token.token() {
+                pgt_query::protobuf::Token::CComment => true,
+                pgt_query::protobuf::Token::SqlComment => false,
+                _ => return None,
+            };
+            let start = token.start as usize;
+            let end = token.end as usize;
+            Some(SourceComment {
+                text: sql.get(start..end)?.to_string(),
+                start,
+                end,
+                is_block,
+            })
+        })
+        .collect()
+}
+
+/// Bind `comments` to whichever of `node_locations` they attach to.
+///
+/// `node_locations` is every AST node's `location` offset in the statement,
+/// collected in one walk over the tree; order and duplicates don't matter.
+/// A comment attaches as trailing to the closest node starting at or before
+/// its start offset when no newline lies between that node's start and the
+/// comment; otherwise it attaches as leading to the next node, or, when no
+/// node follows, as trailing to the preceding one. Only a comment with
+/// neither a preceding nor a following node ends up in
+/// [`CommentMap::orphaned`].
+pub fn attach_comments(sql: &str, comments: Vec<SourceComment>, node_locations: &[i32]) -> CommentMap {
+    let mut locations = node_locations.to_vec();
+    locations.sort_unstable();
+    locations.dedup();
+
+    let mut map = CommentMap::default();
+
+    for comment in comments {
+        let preceding = locations
+            .iter()
+            .rev()
+            .find(|&&loc| loc >= 0 && (loc as usize) <= comment.start)
+            .copied();
+        let following = locations
+            .iter()
+            .find(|&&loc| loc >= 0 && (loc as usize) > comment.start)
+            .copied();
+
+        let same_line_as_preceding = preceding.is_some_and(|prev| {
+            !sql
+                .get(prev as usize..comment.start)
+                .unwrap_or("\n")
+                .contains('\n')
+        });
+
+        match (same_line_as_preceding, preceding, following) {
+            (true, Some(prev), _) => map.trailing.entry(prev).or_default().push(comment),
+            (false, _, Some(next)) => map.leading.entry(next).or_default().push(comment),
+            (false, Some(prev), None) => map.trailing.entry(prev).or_default().push(comment),
+            (false, None, None) => map.orphaned.push(comment),
+        }
+    }
+
+    map
+}
+
+/// Walk `node`'s emission once, with no hook that does anything but record
+/// every `location` [`crate::nodes::emit_node_enum`] calls
+/// [`EventEmitter::enter_node`]/[`EventEmitter::exit_node`] for.
+///
+/// [`attach_comments`] needs the complete set of attachable locations before
+/// it can bucket a single comment as leading or trailing, but this crate has
+/// no tree-walker separate from emission itself - so this throws away a
+/// first emission pass purely to learn those locations, and the real
+/// emission (with a [`CommentHook`] installed) runs second. Wasteful next to
+/// a dedicated AST visitor, but every node kind a comment can attach to
+/// already flows through here, with no separate traversal to keep in sync.
+pub fn collect_locations(node: &pgt_query::NodeEnum) -> Vec<i32> {
+    struct LocationCollector(Rc<RefCell<Vec<i32>>>);
+
+    impl EmitterHook for LocationCollector {
+        fn on_enter(&mut self, _e: &mut EventEmitter, location: i32) {
+            self.0.borrow_mut().push(location);
+        }
+
+        fn on_exit(&mut self, _e: &mut EventEmitter, _location: i32) {}
+    }
+
+    let locations = Rc::new(RefCell::new(Vec::new()));
+    let mut dry_run = EventEmitter::with_hook(Box::new(LocationCollector(Rc::clone(&locations))));
+    crate::nodes::emit_node_enum(node, &mut dry_run);
+    drop(dry_run);
+
+    Rc::try_unwrap(locations)
+        .expect("dry_run dropped above, so this was the only remaining reference")
+        .into_inner()
+}
+
+/// An [`EmitterHook`] that flushes a [`CommentMap`]'s comments as the
+/// emitter passes each node they're bound to.
+pub struct CommentHook {
+    map: CommentMap,
+}
+
+impl CommentHook {
+    pub fn new(map: CommentMap) -> Self {
+        Self { map }
+    }
+}
+
+impl EmitterHook for CommentHook {
+    fn on_enter(&mut self, e: &mut EventEmitter, location: i32) {
+        for comment in self.map.take_leading(location) {
+            e.comment(Comment {
+                text: comment.text,
+                is_block: comment.is_block,
+                position: CommentPosition::Leading,
+            });
+        }
+    }
+
+    fn on_exit(&mut self, e: &mut EventEmitter, location: i32) {
+        for comment in self.map.take_trailing(location) {
+            e.comment(Comment {
+                text: comment.text,
+                is_block: comment.is_block,
+                position: CommentPosition::Trailing,
+            });
+        }
+    }
+}
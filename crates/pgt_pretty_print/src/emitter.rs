@@ -0,0 +1,428 @@
+use std::ops::Range;
+
+use crate::token::TokenKind;
+
+/// Identifies the syntactic construct a group of events belongs to.
+///
+/// Groups don't affect token output on their own; the [`crate::renderer::Renderer`]
+/// uses them as the unit it decides to keep flat or break across lines.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum GroupKind {
+    AExpr,
+    AlterEnumStmt,
+    AlterForeignServerStmt,
+    AlterObjectDependsStmt,
+    AlterObjectSchemaStmt,
+    AlterOwnerStmt,
+    AlterSubscriptionStmt,
+    BoolExpr,
+    Boolean,
+    /// A comma-separated list emitted by [`crate::nodes::node_list::emit_comma_separated_list`],
+    /// wrapping the whole list so it can decide, independently of its
+    /// enclosing statement, whether any of its items need to break.
+    CommaList,
+    CreateCastStmt,
+    CreateForeignServerStmt,
+    CreateSubscriptionStmt,
+    CreateTableAsStmt,
+    CreateTableSpaceStmt,
+    DefineStmt,
+    DeleteStmt,
+    DoStmt,
+    InsertStmt,
+    JoinExpr,
+    JsonFuncExpr,
+    JsonIsPredicate,
+    JsonParseExpr,
+    JsonScalarExpr,
+    JsonTable,
+    LoadStmt,
+    ObjectWithArgs,
+    RenameStmt,
+    ResTarget,
+    RowExpr,
+    ScalarArrayOpExpr,
+    SecLabelStmt,
+    SelectStmt,
+    String,
+    TypeName,
+    ValuesRow,
+    ViewStmt,
+}
+
+/// How a group's [`Event::Line`] breaks render once the group itself has
+/// been decided to break (its flat width doesn't fit, or it contains a
+/// `Hard` line/comment). Mirrors Oppen's `Begin(offset, break-type)`
+/// primitive.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum BreakStyle {
+    /// Every break in the group becomes a newline - the group is "all lines
+    /// or none", e.g. a clause list where a mix of wrapped and unwrapped
+    /// clauses would read as inconsistent formatting.
+    Consistent,
+    /// Each break decides independently, based on whether the content up to
+    /// the *next* break (or the group's end) still fits on the current
+    /// line - e.g. a comma-separated list, where only the items that would
+    /// actually overflow should wrap.
+    Inconsistent,
+}
+
+/// A break hint between two tokens.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum LineType {
+    /// Breaks onto a new line when the enclosing group doesn't fit; otherwise
+    /// produces nothing.
+    Soft,
+    /// Like `Soft`, but produces a space when the enclosing group fits flat.
+    SoftOrSpace,
+    /// Always breaks onto a new line.
+    Hard,
+}
+
+/// Where a [`Comment`] attaches relative to the node it was bound to.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum CommentPosition {
+    /// Printed on its own line before the owning group.
+    Leading,
+    /// Printed after the owning group, on the same line.
+    Trailing,
+}
+
+/// A comment carried through from the source text, bound to the node whose
+/// span most tightly encloses or precedes it. See [`crate::comments`].
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Comment {
+    pub text: String,
+    pub is_block: bool,
+    pub position: CommentPosition,
+}
+
+/// How a top-level statement's emitter decides whether it gets a trailing
+/// `;`. Modeled on rustfmt's `trailing_semicolon` option.
+///
+/// `PreserveAsParsed` and `OmitOnLast` need information a single statement's
+/// emitter doesn't have on its own - whether the source actually had a `;`,
+/// or whether this is the last statement in a multi-statement script - so
+/// until a caller resolves and threads that through, both currently behave
+/// like "omit" rather than `Always`'s "always emit". `crate::format_range`
+/// only ever builds one `EventEmitter` per statement today, so there's no
+/// driver yet that could supply that context; `Always` (the default) is
+/// unaffected either way and keeps today's long-standing behavior.
+///
+/// Only the `SELECT`, `INSERT` and `DELETE` emitters consult this policy;
+/// every other statement emitter always emits `;`.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub enum SemicolonPolicy {
+    #[default]
+    Always,
+    PreserveAsParsed,
+    OmitOnLast,
+}
+
+/// How [`crate::nodes::node_list::emit_comma_separated_list`] punctuates a
+/// list once it actually breaks across multiple lines. Modeled on rustfmt's
+/// `trailing_comma` option.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub enum SeparatorTactic {
+    /// Never wrap the list item-by-item, regardless of width - an enclosing
+    /// group can still wrap around it, but the list itself stays one line.
+    Horizontal,
+    /// Wrap one item per line once the list doesn't fit, with a trailing
+    /// comma after the last item.
+    VerticalTrailingComma,
+    /// Once the list doesn't fit, break only before the items that would
+    /// overflow the line, with no trailing comma after the last item -
+    /// today's long-standing behavior.
+    #[default]
+    Vertical,
+}
+
+/// Formatting choices threaded through [`EventEmitter`] and read by node
+/// emitters via [`EventEmitter::config`], replacing the ad-hoc booleans
+/// (like the `with_semicolon` argument statement emitters used to pass
+/// around their own `_impl` functions by hand) that used to hard-code this
+/// kind of decision per call site.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub struct FormatterConfig {
+    pub semicolon_policy: SemicolonPolicy,
+    pub separator_tactic: SeparatorTactic,
+}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum Event {
+    /// The second field is the token's originating source byte range,
+    /// typically derived from a node's `.location` field, threaded through
+    /// so [`crate::renderer::Renderer::render_with_position_map`] can map
+    /// output offsets back to it. `None` for synthetic tokens the
+    /// pretty-printer inserts on its own (punctuation, inferred keywords,
+    /// ...) that have no span of their own in the source.
+    Token(TokenKind, Option<Range<usize>>),
+    Space,
+    /// Like `Token`'s span field, but for a line break standing in for
+    /// source text (rare - most `Line`s are formatting-only and carry
+    /// `None`).
+    Line(LineType, Option<Range<usize>>),
+    /// The second field is the originating node's byte `location`, threaded
+    /// through so [`crate::renderer::Renderer::render_with_source_map`] can
+    /// report the output range each group occupied. `None` for groups that
+    /// don't correspond to a single source node (or were emitted via the
+    /// plain [`EventEmitter::group_start`]). The third field is the group's
+    /// [`BreakStyle`].
+    GroupStart(GroupKind, Option<i32>, BreakStyle),
+    GroupEnd,
+    IndentStart,
+    IndentEnd,
+    Comment(Comment),
+    /// A token that only renders if the group it's directly nested in
+    /// actually breaks across multiple lines - e.g.
+    /// [`crate::nodes::node_list::emit_comma_separated_list`]'s trailing
+    /// comma under [`SeparatorTactic::VerticalTrailingComma`], which must
+    /// disappear once the list stays flat (`(a, b, c)`, never `(a, b, c,)`).
+    /// Contributes no width to [`crate::renderer::analyze`]'s flat-width
+    /// pass, mirroring how [`LineType::Soft`] is weightless until it
+    /// actually breaks.
+    ConditionalToken(TokenKind),
+}
+
+/// A callback invoked as node emitters enter and leave each AST node,
+/// analogous to a pretty-printer's annotation hook. [`crate::comments::CommentHook`]
+/// is the motivating implementation, flushing comments bound to a node's
+/// `location` as the emitter passes it, but callers can install any
+/// `EmitterHook` to inject their own annotations the same way.
+pub trait EmitterHook {
+    /// Called before a node's own events are pushed. `location` is the
+    /// node's `location` field.
+    fn on_enter(&mut self, e: &mut EventEmitter, location: i32);
+    /// Called after a node's own events are pushed.
+    fn on_exit(&mut self, e: &mut EventEmitter, location: i32);
+}
+
+/// An emitter-side override of [`default_wants_space`] for the very next
+/// token, set by [`EventEmitter::no_space`]/[`EventEmitter::force_space`] and
+/// consumed by the next [`EventEmitter::token`]/[`EventEmitter::token_at`]
+/// call.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+enum SpaceOverride {
+    Auto,
+    Suppressed,
+    Forced,
+}
+
+/// Whether [`EventEmitter::token`] should insert a space between `prev` and
+/// `next` on its own, absent an explicit [`EventEmitter::no_space`]/
+/// [`EventEmitter::force_space`] override. Mirrors how a human typesets two
+/// adjacent tokens: punctuation hugs the token it closes off or follows, and
+/// a keyword that opens its argument list the way a function call does
+/// (`CAST(`, `NULLIF(`, `ROW(`, or any plain identifier/keyword name) doesn't
+/// get a space before its `(` either - but any other pair of word-like
+/// tokens does.
+fn default_wants_space(prev: &TokenKind, next: &TokenKind) -> bool {
+    use TokenKind::*;
+
+    if matches!(next, COMMA | SEMICOLON | R_PAREN | R_BRACK | DOT) {
+        return false;
+    }
+    if matches!(prev, L_PAREN | L_BRACK | DOT) {
+        return false;
+    }
+    if matches!(next, L_PAREN)
+        && matches!(prev, ROW_KW | NULLIF_KW | CAST_KW | IDENT(_) | KEYWORD(_))
+    {
+        return false;
+    }
+    true
+}
+
+/// Builds up the flat event stream that [`crate::renderer::Renderer`] later
+/// turns into formatted text. Node emitters (`nodes::emit_*`) only ever push
+/// events onto an `EventEmitter`; they never touch text directly.
+pub struct EventEmitter {
+    pub events: Vec<Event>,
+    hook: Option<Box<dyn EmitterHook>>,
+    last_token: Option<TokenKind>,
+    space_override: SpaceOverride,
+    config: FormatterConfig,
+}
+
+impl Default for EventEmitter {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl EventEmitter {
+    pub fn new() -> Self {
+        Self::with_config(FormatterConfig::default())
+    }
+
+    /// Like [`Self::new`], but with an explicit [`FormatterConfig`] instead
+    /// of the default.
+    pub fn with_config(config: FormatterConfig) -> Self {
+        Self {
+            events: Vec::new(),
+            hook: None,
+            last_token: None,
+            space_override: SpaceOverride::Auto,
+            config,
+        }
+    }
+
+    /// Install a hook to be notified on node entry/exit. See
+    /// [`Self::enter_node`]/[`Self::exit_node`].
+    pub fn with_hook(hook: Box<dyn EmitterHook>) -> Self {
+        Self::with_hook_and_config(hook, FormatterConfig::default())
+    }
+
+    /// Like [`Self::with_hook`], but with an explicit [`FormatterConfig`]
+    /// instead of the default.
+    pub fn with_hook_and_config(hook: Box<dyn EmitterHook>, config: FormatterConfig) -> Self {
+        Self {
+            events: Vec::new(),
+            hook: Some(hook),
+            last_token: None,
+            space_override: SpaceOverride::Auto,
+            config,
+        }
+    }
+
+    /// The [`FormatterConfig`] node emitters should consult for formatting
+    /// choices, instead of taking their own ad-hoc booleans.
+    pub fn config(&self) -> FormatterConfig {
+        self.config
+    }
+
+    /// Notify the installed hook, if any, that emission of the node at
+    /// `location` is about to begin. Node emitters that have a `location`
+    /// field call this before pushing their own events.
+    pub fn enter_node(&mut self, location: i32) {
+        if let Some(mut hook) = self.hook.take() {
+            hook.on_enter(self, location);
+            self.hook = Some(hook);
+        }
+    }
+
+    /// Notify the installed hook, if any, that emission of the node at
+    /// `location` has finished. See [`Self::enter_node`].
+    pub fn exit_node(&mut self, location: i32) {
+        if let Some(mut hook) = self.hook.take() {
+            hook.on_exit(self, location);
+            self.hook = Some(hook);
+        }
+    }
+
+    pub fn token(&mut self, kind: TokenKind) {
+        self.auto_space(&kind);
+        self.events.push(Event::Token(kind.clone(), None));
+        self.last_token = Some(kind);
+    }
+
+    /// Like [`Self::token`], but records the source byte range (typically a
+    /// node's `.location` field through its end) this token was rendered
+    /// from, so [`crate::renderer::Renderer::render_with_position_map`] can
+    /// later map output offsets back to it.
+    pub fn token_at(&mut self, kind: TokenKind, span: Range<usize>) {
+        self.auto_space(&kind);
+        self.events.push(Event::Token(kind.clone(), Some(span)));
+        self.last_token = Some(kind);
+    }
+
+    /// Push a token that only renders if the group it's directly nested in
+    /// ends up breaking across multiple lines. See [`Event::ConditionalToken`].
+    pub fn conditional_token(&mut self, kind: TokenKind) {
+        self.events.push(Event::ConditionalToken(kind));
+    }
+
+    /// Insert a space before `kind` if [`default_wants_space`] (or an
+    /// override set by [`Self::no_space`]/[`Self::force_space`]) calls for
+    /// one. Called by `token`/`token_at` so emitters can rely on correct
+    /// spacing between adjacent tokens without writing `self.space()`
+    /// themselves; a manually-written `space()` call still works exactly as
+    /// before; the renderer collapses any resulting doubled-up spaces.
+    fn auto_space(&mut self, kind: &TokenKind) {
+        let wants_space = match self.space_override {
+            SpaceOverride::Suppressed => false,
+            SpaceOverride::Forced => true,
+            SpaceOverride::Auto => self
+                .last_token
+                .as_ref()
+                .is_some_and(|prev| default_wants_space(prev, kind)),
+        };
+        self.space_override = SpaceOverride::Auto;
+        if wants_space {
+            self.space();
+        }
+    }
+
+    /// Suppress the automatic space [`Self::token`]/[`Self::token_at`] would
+    /// otherwise insert before the next token, for the rare case where
+    /// [`default_wants_space`]'s default is wrong for the token about to be
+    /// emitted.
+    pub fn no_space(&mut self) {
+        self.space_override = SpaceOverride::Suppressed;
+    }
+
+    /// Force a space before the next token even where [`default_wants_space`]
+    /// would otherwise omit one (e.g. a keyword that takes a parenthesized
+    /// argument list most of the time, but not this one).
+    pub fn force_space(&mut self) {
+        self.space_override = SpaceOverride::Forced;
+    }
+
+    pub fn space(&mut self) {
+        self.events.push(Event::Space);
+    }
+
+    pub fn line(&mut self, kind: LineType) {
+        self.events.push(Event::Line(kind, None));
+    }
+
+    /// Like [`Self::line`], but records the source byte range this break
+    /// stands in for. See [`Self::token_at`].
+    pub fn line_at(&mut self, kind: LineType, span: Range<usize>) {
+        self.events.push(Event::Line(kind, Some(span)));
+    }
+
+    /// Start a [`BreakStyle::Consistent`] group - the style every group
+    /// used before `BreakStyle` existed, and still the right choice for a
+    /// statement or clause list where a mix of wrapped/unwrapped lines
+    /// would look broken rather than intentional.
+    pub fn group_start(&mut self, kind: GroupKind) {
+        self.events
+            .push(Event::GroupStart(kind, None, BreakStyle::Consistent));
+    }
+
+    /// Like [`Self::group_start`], but records the byte offset (a node's
+    /// `.location` field) the group originated from, so a source map can
+    /// later link this group's rendered range back to it.
+    pub fn group_start_at(&mut self, kind: GroupKind, location: i32) {
+        self.events
+            .push(Event::GroupStart(kind, Some(location), BreakStyle::Consistent));
+    }
+
+    /// Like [`Self::group_start`], but [`BreakStyle::Inconsistent`] - for a
+    /// comma-separated list, where only the items that actually overflow
+    /// the margin should wrap.
+    pub fn group_start_inconsistent(&mut self, kind: GroupKind) {
+        self.events
+            .push(Event::GroupStart(kind, None, BreakStyle::Inconsistent));
+    }
+
+    pub fn group_end(&mut self) {
+        self.events.push(Event::GroupEnd);
+    }
+
+    pub fn indent_start(&mut self) {
+        self.events.push(Event::IndentStart);
+    }
+
+    pub fn indent_end(&mut self) {
+        self.events.push(Event::IndentEnd);
+    }
+
+    /// Flush a comment bound to the node currently being emitted. Leading
+    /// comments should be emitted before the node's group starts; trailing
+    /// comments after it ends.
+    pub fn comment(&mut self, comment: Comment) {
+        self.events.push(Event::Comment(comment));
+    }
+}
@@ -0,0 +1,490 @@
+//! PostgreSQL keyword categorization, mirroring the four buckets
+//! `kwlist.h` sorts every keyword into. Kept as sorted, lowercase static
+//! slices in the style of rust-analyzer's generated `syntax_kind` tables,
+//! rather than hand-maintained as prose: the categories come straight from
+//! the grammar and should be regenerated from `kwlist.h` rather than edited
+//! piecemeal.
+//!
+//! Quoting only cares about two buckets: [`KeywordCategory::Reserved`] and
+//! [`KeywordCategory::TypeFuncName`] can never appear unquoted wherever an
+//! identifier is expected in a context that's also valid for a keyword
+//! (e.g. a bare column reference), so [`classify`] is the single
+//! source of truth other emitters should share instead of re-deriving their
+//! own keyword lists.
+
+/// Which of the four `kwlist.h` categories a keyword belongs to. Lower
+/// variants are less restricted: `Unreserved` keywords are valid as any
+/// identifier, `Reserved` ones can't be used as an identifier anywhere.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum KeywordCategory {
+    Unreserved,
+    ColName,
+    TypeFuncName,
+    Reserved,
+}
+
+/// Look up the category of `word`, which must already be lowercased.
+/// Returns `None` if `word` isn't a PostgreSQL keyword at all.
+pub fn classify(word: &str) -> Option<KeywordCategory> {
+    if RESERVED.binary_search(&word).is_ok() {
+        Some(KeywordCategory::Reserved)
+    } else if TYPE_FUNC_NAME.binary_search(&word).is_ok() {
+        Some(KeywordCategory::TypeFuncName)
+    } else if COL_NAME.binary_search(&word).is_ok() {
+        Some(KeywordCategory::ColName)
+    } else if UNRESERVED.binary_search(&word).is_ok() {
+        Some(KeywordCategory::Unreserved)
+    } else {
+        None
+    }
+}
+
+/// Can never be used as an identifier, column label, or type/function name.
+const RESERVED: &[&str] = &[
+    "all",
+    "analyse",
+    "analyze",
+    "and",
+    "any",
+    "array",
+    "as",
+    "asc",
+    "asymmetric",
+    "both",
+    "case",
+    "cast",
+    "check",
+    "collate",
+    "column",
+    "constraint",
+    "create",
+    "current_catalog",
+    "current_date",
+    "current_role",
+    "current_time",
+    "current_timestamp",
+    "current_user",
+    "default",
+    "deferrable",
+    "desc",
+    "distinct",
+    "do",
+    "else",
+    "end",
+    "except",
+    "false",
+    "fetch",
+    "for",
+    "foreign",
+    "from",
+    "grant",
+    "group",
+    "having",
+    "in",
+    "initially",
+    "intersect",
+    "into",
+    "lateral",
+    "leading",
+    "limit",
+    "localtime",
+    "localtimestamp",
+    "not",
+    "null",
+    "offset",
+    "on",
+    "only",
+    "or",
+    "order",
+    "placing",
+    "primary",
+    "references",
+    "returning",
+    "select",
+    "session_user",
+    "some",
+    "symmetric",
+    "table",
+    "then",
+    "to",
+    "trailing",
+    "true",
+    "union",
+    "unique",
+    "user",
+    "using",
+    "variadic",
+    "when",
+    "where",
+    "window",
+    "with",
+];
+
+/// Reserved, but may be used as a function or type name.
+const TYPE_FUNC_NAME: &[&str] = &[
+    "authorization",
+    "binary",
+    "collation",
+    "concurrently",
+    "cross",
+    "current_schema",
+    "freeze",
+    "full",
+    "ilike",
+    "inner",
+    "is",
+    "isnull",
+    "join",
+    "left",
+    "like",
+    "natural",
+    "notnull",
+    "outer",
+    "overlaps",
+    "right",
+    "similar",
+    "tablesample",
+    "verbose",
+];
+
+/// May be used as a column label or table alias, but not as a bare function
+/// or type name.
+const COL_NAME: &[&str] = &[
+    "between",
+    "bigint",
+    "bit",
+    "boolean",
+    "char",
+    "character",
+    "coalesce",
+    "dec",
+    "decimal",
+    "exists",
+    "extract",
+    "float",
+    "greatest",
+    "grouping",
+    "groups",
+    "inout",
+    "int",
+    "integer",
+    "interval",
+    "least",
+    "national",
+    "nchar",
+    "none",
+    "normalize",
+    "nullif",
+    "numeric",
+    "out",
+    "overlay",
+    "position",
+    "precision",
+    "real",
+    "row",
+    "setof",
+    "smallint",
+    "substring",
+    "time",
+    "timestamp",
+    "treat",
+    "trim",
+    "values",
+    "varchar",
+    "xmlattributes",
+    "xmlconcat",
+    "xmlelement",
+    "xmlexists",
+    "xmlforest",
+    "xmlnamespaces",
+    "xmlparse",
+    "xmlpi",
+    "xmlroot",
+    "xmlserialize",
+    "xmltable",
+];
+
+/// May always be used as an identifier.
+const UNRESERVED: &[&str] = &[
+    "abort",
+    "absolute",
+    "access",
+    "action",
+    "add",
+    "admin",
+    "after",
+    "aggregate",
+    "also",
+    "alter",
+    "always",
+    "assertion",
+    "assignment",
+    "at",
+    "attach",
+    "attribute",
+    "backward",
+    "before",
+    "begin",
+    "by",
+    "cache",
+    "called",
+    "cascade",
+    "cascaded",
+    "catalog",
+    "chain",
+    "characteristics",
+    "checkpoint",
+    "class",
+    "close",
+    "cluster",
+    "comment",
+    "comments",
+    "commit",
+    "committed",
+    "configuration",
+    "conflict",
+    "connection",
+    "constraints",
+    "content",
+    "continue",
+    "conversion",
+    "copy",
+    "cost",
+    "csv",
+    "cursor",
+    "cycle",
+    "data",
+    "database",
+    "day",
+    "deallocate",
+    "declare",
+    "defaults",
+    "deferred",
+    "definer",
+    "delete",
+    "delimiter",
+    "delimiters",
+    "depends",
+    "detach",
+    "dictionary",
+    "disable",
+    "discard",
+    "document",
+    "domain",
+    "double",
+    "drop",
+    "each",
+    "enable",
+    "encoding",
+    "encrypted",
+    "enum",
+    "escape",
+    "event",
+    "exclude",
+    "excluding",
+    "exclusive",
+    "execute",
+    "explain",
+    "expression",
+    "extension",
+    "external",
+    "family",
+    "filter",
+    "first",
+    "following",
+    "force",
+    "forward",
+    "function",
+    "functions",
+    "generated",
+    "global",
+    "granted",
+    "handler",
+    "header",
+    "hold",
+    "hour",
+    "identity",
+    "if",
+    "immediate",
+    "immutable",
+    "implicit",
+    "import",
+    "include",
+    "including",
+    "increment",
+    "index",
+    "indexes",
+    "inherit",
+    "inherits",
+    "insensitive",
+    "insert",
+    "instead",
+    "invoker",
+    "isolation",
+    "key",
+    "label",
+    "language",
+    "large",
+    "last",
+    "leakproof",
+    "level",
+    "listen",
+    "load",
+    "local",
+    "location",
+    "lock",
+    "locked",
+    "logged",
+    "mapping",
+    "match",
+    "materialized",
+    "maxvalue",
+    "method",
+    "minute",
+    "minvalue",
+    "mode",
+    "month",
+    "move",
+    "name",
+    "names",
+    "next",
+    "no",
+    "nothing",
+    "notify",
+    "nowait",
+    "nulls",
+    "object",
+    "of",
+    "off",
+    "oids",
+    "operator",
+    "option",
+    "options",
+    "ordinality",
+    "others",
+    "over",
+    "overriding",
+    "owned",
+    "owner",
+    "parallel",
+    "parser",
+    "partial",
+    "partition",
+    "passing",
+    "password",
+    "plans",
+    "policy",
+    "preceding",
+    "prepare",
+    "prepared",
+    "preserve",
+    "prior",
+    "privileges",
+    "procedural",
+    "procedure",
+    "procedures",
+    "program",
+    "publication",
+    "quote",
+    "range",
+    "read",
+    "reassign",
+    "recheck",
+    "recursive",
+    "ref",
+    "referencing",
+    "refresh",
+    "reindex",
+    "relative",
+    "release",
+    "rename",
+    "repeatable",
+    "replace",
+    "replica",
+    "reset",
+    "restart",
+    "restrict",
+    "revoke",
+    "role",
+    "rollback",
+    "rollup",
+    "routine",
+    "routines",
+    "rule",
+    "savepoint",
+    "schema",
+    "schemas",
+    "scroll",
+    "search",
+    "second",
+    "security",
+    "sequence",
+    "sequences",
+    "serializable",
+    "server",
+    "session",
+    "set",
+    "sets",
+    "share",
+    "show",
+    "simple",
+    "skip",
+    "snapshot",
+    "stable",
+    "standalone",
+    "start",
+    "statement",
+    "statistics",
+    "stdin",
+    "stdout",
+    "storage",
+    "stored",
+    "strict",
+    "strip",
+    "subscription",
+    "support",
+    "sysid",
+    "system",
+    "tables",
+    "tablespace",
+    "temp",
+    "template",
+    "temporary",
+    "text",
+    "ties",
+    "transaction",
+    "transform",
+    "trigger",
+    "trusted",
+    "type",
+    "types",
+    "uescape",
+    "unbounded",
+    "uncommitted",
+    "unencrypted",
+    "unknown",
+    "unlisten",
+    "unlogged",
+    "until",
+    "update",
+    "vacuum",
+    "valid",
+    "validate",
+    "validator",
+    "value",
+    "varying",
+    "version",
+    "view",
+    "views",
+    "volatile",
+    "whitespace",
+    "within",
+    "without",
+    "work",
+    "wrapper",
+    "write",
+    "xml",
+    "year",
+    "yes",
+    "zone",
+];
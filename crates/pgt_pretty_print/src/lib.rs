@@ -0,0 +1,25 @@
+pub mod comments;
+pub mod emitter;
+pub mod format;
+pub mod keywords;
+pub mod nodes;
+pub mod renderer;
+pub mod token;
+
+pub use format::format_range;
+pub use token::TokenKind;
+
+/// Pulls the given `NodeEnum` variant out of a `&Node`, panicking with a
+/// descriptive message otherwise. For spots where the protobuf schema
+/// guarantees the shape (e.g. every item of an `OPTIONS` list is a
+/// `DefElem`) but there's no combinator for a refutable match inside a
+/// closure passed to `emit_comma_separated_list`.
+macro_rules! assert_node_variant {
+    ($variant:ident, $node:expr) => {
+        match $node.node.as_ref() {
+            Some(pgt_query::NodeEnum::$variant(inner)) => inner,
+            other => panic!("expected {} node, got {:?}", stringify!($variant), other),
+        }
+    };
+}
+pub(crate) use assert_node_variant;
@@ -3,10 +3,15 @@ use pgt_query::protobuf::{AExpr, AExprKind};
 use crate::{
     TokenKind,
     emitter::{EventEmitter, GroupKind},
+    nodes::precedence::{Precedence, Side, emit_operand, operator_precedence},
 };
 
 pub(super) fn emit_a_expr(e: &mut EventEmitter, n: &AExpr) {
-    e.group_start(GroupKind::AExpr);
+    if n.location >= 0 {
+        e.group_start_at(GroupKind::AExpr, n.location);
+    } else {
+        e.group_start(GroupKind::AExpr);
+    }
 
     match n.kind() {
         AExprKind::AexprOp => emit_aexpr_op(e, n),
@@ -29,29 +34,39 @@ pub(super) fn emit_a_expr(e: &mut EventEmitter, n: &AExpr) {
     e.group_end();
 }
 
-// Basic binary operator: left op right
+// Basic binary operator: left op right (or unary: op right, when lexpr is
+// absent - e.g. unary minus).
 fn emit_aexpr_op(e: &mut EventEmitter, n: &AExpr) {
+    let is_unary = n.lexpr.is_none();
+    let parent = if is_unary {
+        Precedence::UnaryMinus
+    } else {
+        operator_precedence(&n.name).0
+    };
+
     if let Some(ref lexpr) = n.lexpr {
-        super::emit_node(lexpr, e);
+        emit_operand(e, lexpr, parent, Side::Left);
+        e.space();
     }
 
-    if !n.name.is_empty() {
-        e.space();
-        for name in &n.name {
-            super::emit_node(name, e);
-        }
-        e.space();
+    for name in &n.name {
+        super::emit_node(name, e);
     }
 
     if let Some(ref rexpr) = n.rexpr {
-        super::emit_node(rexpr, e);
+        if !is_unary {
+            e.space();
+        }
+        emit_operand(e, rexpr, parent, Side::Right);
     }
 }
 
 // expr op ANY (subquery)
 fn emit_aexpr_op_any(e: &mut EventEmitter, n: &AExpr) {
+    let parent = operator_precedence(&n.name).0;
+
     if let Some(ref lexpr) = n.lexpr {
-        super::emit_node(lexpr, e);
+        emit_operand(e, lexpr, parent, Side::Left);
         e.space();
     }
 
@@ -72,8 +87,10 @@ fn emit_aexpr_op_any(e: &mut EventEmitter, n: &AExpr) {
 
 // expr op ALL (subquery)
 fn emit_aexpr_op_all(e: &mut EventEmitter, n: &AExpr) {
+    let parent = operator_precedence(&n.name).0;
+
     if let Some(ref lexpr) = n.lexpr {
-        super::emit_node(lexpr, e);
+        emit_operand(e, lexpr, parent, Side::Left);
         e.space();
     }
 
@@ -95,7 +112,7 @@ fn emit_aexpr_op_all(e: &mut EventEmitter, n: &AExpr) {
 // expr IS DISTINCT FROM expr2
 fn emit_aexpr_distinct(e: &mut EventEmitter, n: &AExpr) {
     if let Some(ref lexpr) = n.lexpr {
-        super::emit_node(lexpr, e);
+        emit_operand(e, lexpr, Precedence::Comparison, Side::Left);
         e.space();
     }
 
@@ -107,14 +124,14 @@ fn emit_aexpr_distinct(e: &mut EventEmitter, n: &AExpr) {
     e.space();
 
     if let Some(ref rexpr) = n.rexpr {
-        super::emit_node(rexpr, e);
+        emit_operand(e, rexpr, Precedence::Comparison, Side::Right);
     }
 }
 
 // expr IS NOT DISTINCT FROM expr2
 fn emit_aexpr_not_distinct(e: &mut EventEmitter, n: &AExpr) {
     if let Some(ref lexpr) = n.lexpr {
-        super::emit_node(lexpr, e);
+        emit_operand(e, lexpr, Precedence::Comparison, Side::Left);
         e.space();
     }
 
@@ -128,7 +145,7 @@ fn emit_aexpr_not_distinct(e: &mut EventEmitter, n: &AExpr) {
     e.space();
 
     if let Some(ref rexpr) = n.rexpr {
-        super::emit_node(rexpr, e);
+        emit_operand(e, rexpr, Precedence::Comparison, Side::Right);
     }
 }
 
@@ -154,101 +171,145 @@ fn emit_aexpr_nullif(e: &mut EventEmitter, n: &AExpr) {
 // expr IN (values)
 fn emit_aexpr_in(e: &mut EventEmitter, n: &AExpr) {
     if let Some(ref lexpr) = n.lexpr {
-        super::emit_node(lexpr, e);
+        emit_operand(e, lexpr, Precedence::PatternMatch, Side::Left);
         e.space();
     }
 
     e.token(TokenKind::IN_KW);
     e.space();
 
-    // The rexpr is typically a List node, which emits comma-separated items
-    // We need to wrap it in parentheses for IN clause
     e.token(TokenKind::L_PAREN);
     if let Some(ref rexpr) = n.rexpr {
-        super::emit_node(rexpr, e);
+        // rexpr is a `List` node - not a standalone node kind `emit_node`
+        // dispatches on its own (see `nodes::emit_node`'s doc comment) - so
+        // its items are unwrapped and emitted comma-separated here.
+        if let Some(pgt_query::NodeEnum::List(list)) = rexpr.node.as_ref() {
+            super::emit_comma_separated_list(e, &list.items, |item, e| {
+                emit_operand(e, item, Precedence::PatternMatch, Side::Left);
+            });
+        } else {
+            super::emit_node(rexpr, e);
+        }
     }
     e.token(TokenKind::R_PAREN);
 }
 
-// expr LIKE pattern [ESCAPE escape]
+// expr [NOT] LIKE pattern [ESCAPE escape]
 fn emit_aexpr_like(e: &mut EventEmitter, n: &AExpr) {
     if let Some(ref lexpr) = n.lexpr {
-        super::emit_node(lexpr, e);
+        emit_operand(e, lexpr, Precedence::PatternMatch, Side::Left);
         e.space();
     }
 
+    emit_not_prefix(e, &n.name);
     e.token(TokenKind::LIKE_KW);
     e.space();
 
     if let Some(ref rexpr) = n.rexpr {
-        super::emit_node(rexpr, e);
+        emit_pattern_match_rexpr(e, rexpr);
     }
 }
 
-// expr ILIKE pattern [ESCAPE escape]
+// expr [NOT] ILIKE pattern [ESCAPE escape]
 fn emit_aexpr_ilike(e: &mut EventEmitter, n: &AExpr) {
     if let Some(ref lexpr) = n.lexpr {
-        super::emit_node(lexpr, e);
+        emit_operand(e, lexpr, Precedence::PatternMatch, Side::Left);
         e.space();
     }
 
+    emit_not_prefix(e, &n.name);
     e.token(TokenKind::ILIKE_KW);
     e.space();
 
     if let Some(ref rexpr) = n.rexpr {
-        super::emit_node(rexpr, e);
+        emit_pattern_match_rexpr(e, rexpr);
     }
 }
 
-// expr SIMILAR TO pattern [ESCAPE escape]
+// expr [NOT] SIMILAR TO pattern [ESCAPE escape]
 fn emit_aexpr_similar(e: &mut EventEmitter, n: &AExpr) {
     if let Some(ref lexpr) = n.lexpr {
-        super::emit_node(lexpr, e);
+        emit_operand(e, lexpr, Precedence::PatternMatch, Side::Left);
         e.space();
     }
 
+    emit_not_prefix(e, &n.name);
     e.token(TokenKind::SIMILAR_KW);
     e.space();
     e.token(TokenKind::TO_KW);
     e.space();
 
     if let Some(ref rexpr) = n.rexpr {
-        super::emit_node(rexpr, e);
+        emit_pattern_match_rexpr(e, rexpr);
+    }
+}
+
+// Postgres doesn't give `NOT LIKE`/`NOT ILIKE`/`NOT SIMILAR TO` their own
+// `AExprKind` - it reuses the positive kind and negates by prefixing the
+// operator name with `!` (`~~` vs `!~~`, `~~*` vs `!~~*`, `~` vs `!~`), so
+// check for that prefix here rather than in each predicate's own emitter.
+fn emit_not_prefix(e: &mut EventEmitter, name: &[pgt_query::Node]) {
+    let is_negated = name
+        .last()
+        .and_then(|n| match n.node.as_ref() {
+            Some(pgt_query::NodeEnum::String(s)) => Some(s.sval.as_str()),
+            _ => None,
+        })
+        .is_some_and(|op| op.starts_with('!'));
+
+    if is_negated {
+        e.token(TokenKind::NOT_KW);
+        e.space();
     }
 }
 
+// `LIKE`/`ILIKE`/`SIMILAR TO` have no dedicated `escape` field on `AExpr` -
+// Postgres instead encodes `ESCAPE escape` by wrapping the pattern in a
+// `like_escape`/`similar_to_escape` `FuncCall` whose first argument is the
+// pattern and second is the escape string. Detect that shape and unwrap it
+// back into `pattern ESCAPE escape`, falling back to emitting `rexpr`
+// as-is when no escape wrapper is present.
+fn emit_pattern_match_rexpr(e: &mut EventEmitter, rexpr: &pgt_query::Node) {
+    if let Some(pgt_query::NodeEnum::FuncCall(call)) = rexpr.node.as_ref() {
+        let is_escape_wrapper = call
+            .funcname
+            .last()
+            .and_then(|name| match name.node.as_ref() {
+                Some(pgt_query::NodeEnum::String(s)) => Some(s.sval.as_str()),
+                _ => None,
+            })
+            .is_some_and(|name| name == "like_escape" || name == "similar_to_escape");
+
+        if let ([pattern, escape], true) = (call.args.as_slice(), is_escape_wrapper) {
+            emit_operand(e, pattern, Precedence::PatternMatch, Side::Right);
+            e.space();
+            e.token(TokenKind::ESCAPE_KW);
+            e.space();
+            emit_operand(e, escape, Precedence::PatternMatch, Side::Right);
+            return;
+        }
+    }
+
+    emit_operand(e, rexpr, Precedence::PatternMatch, Side::Right);
+}
+
 // expr BETWEEN expr2 AND expr3
 fn emit_aexpr_between(e: &mut EventEmitter, n: &AExpr) {
     if let Some(ref lexpr) = n.lexpr {
-        super::emit_node(lexpr, e);
+        emit_operand(e, lexpr, Precedence::PatternMatch, Side::Left);
         e.space();
     }
 
     e.token(TokenKind::BETWEEN_KW);
     e.space();
 
-    // rexpr is a List node with two elements, but we need "expr AND expr" not "expr, expr"
-    if let Some(ref rexpr) = n.rexpr {
-        if let Some(pgt_query::NodeEnum::List(list)) = rexpr.node.as_ref() {
-            if list.items.len() >= 1 {
-                super::emit_node(&list.items[0], e);
-            }
-            if list.items.len() >= 2 {
-                e.space();
-                e.token(TokenKind::AND_KW);
-                e.space();
-                super::emit_node(&list.items[1], e);
-            }
-        } else {
-            super::emit_node(rexpr, e);
-        }
-    }
+    emit_between_bounds(e, n.rexpr.as_ref());
 }
 
 // expr NOT BETWEEN expr2 AND expr3
 fn emit_aexpr_not_between(e: &mut EventEmitter, n: &AExpr) {
     if let Some(ref lexpr) = n.lexpr {
-        super::emit_node(lexpr, e);
+        emit_operand(e, lexpr, Precedence::PatternMatch, Side::Left);
         e.space();
     }
 
@@ -257,28 +318,13 @@ fn emit_aexpr_not_between(e: &mut EventEmitter, n: &AExpr) {
     e.token(TokenKind::BETWEEN_KW);
     e.space();
 
-    // rexpr is a List node with two elements, but we need "expr AND expr" not "expr, expr"
-    if let Some(ref rexpr) = n.rexpr {
-        if let Some(pgt_query::NodeEnum::List(list)) = rexpr.node.as_ref() {
-            if list.items.len() >= 1 {
-                super::emit_node(&list.items[0], e);
-            }
-            if list.items.len() >= 2 {
-                e.space();
-                e.token(TokenKind::AND_KW);
-                e.space();
-                super::emit_node(&list.items[1], e);
-            }
-        } else {
-            super::emit_node(rexpr, e);
-        }
-    }
+    emit_between_bounds(e, n.rexpr.as_ref());
 }
 
 // expr BETWEEN SYMMETRIC expr2 AND expr3
 fn emit_aexpr_between_sym(e: &mut EventEmitter, n: &AExpr) {
     if let Some(ref lexpr) = n.lexpr {
-        super::emit_node(lexpr, e);
+        emit_operand(e, lexpr, Precedence::PatternMatch, Side::Left);
         e.space();
     }
 
@@ -287,28 +333,13 @@ fn emit_aexpr_between_sym(e: &mut EventEmitter, n: &AExpr) {
     e.token(TokenKind::SYMMETRIC_KW);
     e.space();
 
-    // rexpr is a List node with two elements, but we need "expr AND expr" not "expr, expr"
-    if let Some(ref rexpr) = n.rexpr {
-        if let Some(pgt_query::NodeEnum::List(list)) = rexpr.node.as_ref() {
-            if list.items.len() >= 1 {
-                super::emit_node(&list.items[0], e);
-            }
-            if list.items.len() >= 2 {
-                e.space();
-                e.token(TokenKind::AND_KW);
-                e.space();
-                super::emit_node(&list.items[1], e);
-            }
-        } else {
-            super::emit_node(rexpr, e);
-        }
-    }
+    emit_between_bounds(e, n.rexpr.as_ref());
 }
 
 // expr NOT BETWEEN SYMMETRIC expr2 AND expr3
 fn emit_aexpr_not_between_sym(e: &mut EventEmitter, n: &AExpr) {
     if let Some(ref lexpr) = n.lexpr {
-        super::emit_node(lexpr, e);
+        emit_operand(e, lexpr, Precedence::PatternMatch, Side::Left);
         e.space();
     }
 
@@ -319,20 +350,25 @@ fn emit_aexpr_not_between_sym(e: &mut EventEmitter, n: &AExpr) {
     e.token(TokenKind::SYMMETRIC_KW);
     e.space();
 
-    // rexpr is a List node with two elements, but we need "expr AND expr" not "expr, expr"
-    if let Some(ref rexpr) = n.rexpr {
-        if let Some(pgt_query::NodeEnum::List(list)) = rexpr.node.as_ref() {
-            if list.items.len() >= 1 {
-                super::emit_node(&list.items[0], e);
-            }
-            if list.items.len() >= 2 {
-                e.space();
-                e.token(TokenKind::AND_KW);
-                e.space();
-                super::emit_node(&list.items[1], e);
-            }
-        } else {
-            super::emit_node(rexpr, e);
+    emit_between_bounds(e, n.rexpr.as_ref());
+}
+
+// rexpr is a `List` node with two elements (the BETWEEN bounds), but we need
+// "expr2 AND expr3", not "expr2, expr3".
+fn emit_between_bounds(e: &mut EventEmitter, rexpr: Option<&pgt_query::Node>) {
+    let Some(rexpr) = rexpr else { return };
+
+    if let Some(pgt_query::NodeEnum::List(list)) = rexpr.node.as_ref() {
+        if let Some(lower) = list.items.first() {
+            emit_operand(e, lower, Precedence::PatternMatch, Side::Left);
         }
+        if let Some(upper) = list.items.get(1) {
+            e.space();
+            e.token(TokenKind::AND_KW);
+            e.space();
+            emit_operand(e, upper, Precedence::PatternMatch, Side::Right);
+        }
+    } else {
+        super::emit_node(rexpr, e);
     }
 }
@@ -6,5 +6,5 @@ use crate::{
 };
 
 pub(super) fn emit_a_star(e: &mut EventEmitter, _n: &AStar) {
-    e.token(TokenKind::IDENT("*".to_string()))
+    e.token(TokenKind::IDENT("*".into()))
 }
@@ -9,7 +9,7 @@ pub(super) fn emit_alter_enum_stmt(e: &mut EventEmitter, n: &AlterEnumStmt) {
 
     e.token(TokenKind::ALTER_KW);
     e.space();
-    e.token(TokenKind::IDENT("TYPE".to_string()));
+    e.token(TokenKind::KEYWORD("TYPE".into()));
     e.space();
 
     // Enum type name (qualified)
@@ -22,20 +22,20 @@ pub(super) fn emit_alter_enum_stmt(e: &mut EventEmitter, n: &AlterEnumStmt) {
     // Check if this is ADD VALUE or RENAME VALUE
     if !n.old_val.is_empty() {
         // RENAME VALUE old TO new
-        e.token(TokenKind::IDENT("RENAME".to_string()));
+        e.token(TokenKind::KEYWORD("RENAME".into()));
         e.space();
-        e.token(TokenKind::IDENT("VALUE".to_string()));
+        e.token(TokenKind::KEYWORD("VALUE".into()));
         e.space();
-        e.token(TokenKind::IDENT(format!("'{}'", n.old_val)));
+        e.token(TokenKind::IDENT(format!("'{}'", n.old_val).into()));
         e.space();
         e.token(TokenKind::TO_KW);
         e.space();
-        e.token(TokenKind::IDENT(format!("'{}'", n.new_val)));
+        e.token(TokenKind::IDENT(format!("'{}'", n.new_val).into()));
     } else {
         // ADD VALUE [ IF NOT EXISTS ] new_value [ BEFORE old_value | AFTER old_value ]
         e.token(TokenKind::ADD_KW);
         e.space();
-        e.token(TokenKind::IDENT("VALUE".to_string()));
+        e.token(TokenKind::KEYWORD("VALUE".into()));
 
         if n.skip_if_new_val_exists {
             e.space();
@@ -48,19 +48,19 @@ pub(super) fn emit_alter_enum_stmt(e: &mut EventEmitter, n: &AlterEnumStmt) {
 
         if !n.new_val.is_empty() {
             e.space();
-            e.token(TokenKind::IDENT(format!("'{}'", n.new_val)));
+            e.token(TokenKind::IDENT(format!("'{}'", n.new_val).into()));
         }
 
         // Optional BEFORE/AFTER clause
         if !n.new_val_neighbor.is_empty() {
             e.space();
             if n.new_val_is_after {
-                e.token(TokenKind::IDENT("AFTER".to_string()));
+                e.token(TokenKind::KEYWORD("AFTER".into()));
             } else {
-                e.token(TokenKind::IDENT("BEFORE".to_string()));
+                e.token(TokenKind::KEYWORD("BEFORE".into()));
             }
             e.space();
-            e.token(TokenKind::IDENT(format!("'{}'", n.new_val_neighbor)));
+            e.token(TokenKind::IDENT(format!("'{}'", n.new_val_neighbor).into()));
         }
     }
 
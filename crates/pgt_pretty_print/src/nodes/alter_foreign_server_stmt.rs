@@ -9,26 +9,26 @@ pub(super) fn emit_alter_foreign_server_stmt(e: &mut EventEmitter, n: &AlterFore
 
     e.token(TokenKind::ALTER_KW);
     e.space();
-    e.token(TokenKind::IDENT("SERVER".to_string()));
+    e.token(TokenKind::KEYWORD("SERVER".into()));
     e.space();
 
     if !n.servername.is_empty() {
-        e.token(TokenKind::IDENT(n.servername.clone()));
+        e.token(TokenKind::IDENT(n.servername.clone().into()));
     }
 
     if n.has_version && !n.version.is_empty() {
         e.line(LineType::SoftOrSpace);
         e.indent_start();
-        e.token(TokenKind::IDENT("VERSION".to_string()));
+        e.token(TokenKind::KEYWORD("VERSION".into()));
         e.space();
-        e.token(TokenKind::IDENT(format!("'{}'", n.version)));
+        e.token(TokenKind::IDENT(format!("'{}'", n.version).into()));
         e.indent_end();
     }
 
     if !n.options.is_empty() {
         e.line(LineType::SoftOrSpace);
         e.indent_start();
-        e.token(TokenKind::IDENT("OPTIONS".to_string()));
+        e.token(TokenKind::KEYWORD("OPTIONS".into()));
         e.space();
         e.token(TokenKind::L_PAREN);
         emit_comma_separated_list(e, &n.options, |n, e| {
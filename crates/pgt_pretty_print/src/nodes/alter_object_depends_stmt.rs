@@ -15,7 +15,7 @@ pub(super) fn emit_alter_object_depends_stmt(e: &mut EventEmitter, n: &AlterObje
         Ok(ObjectType::ObjectRoutine) => "ROUTINE",
         _ => "UNKNOWN",
     };
-    e.token(TokenKind::IDENT(object_type_str.to_string()));
+    e.token(TokenKind::KEYWORD(object_type_str.into()));
     e.space();
 
     // Object name
@@ -26,19 +26,19 @@ pub(super) fn emit_alter_object_depends_stmt(e: &mut EventEmitter, n: &AlterObje
     e.space();
 
     if n.remove {
-        e.token(TokenKind::IDENT("NO".to_string()));
+        e.token(TokenKind::KEYWORD("NO".into()));
         e.space();
     }
 
-    e.token(TokenKind::IDENT("DEPENDS".to_string()));
+    e.token(TokenKind::KEYWORD("DEPENDS".into()));
     e.space();
     e.token(TokenKind::ON_KW);
     e.space();
-    e.token(TokenKind::IDENT("EXTENSION".to_string()));
+    e.token(TokenKind::KEYWORD("EXTENSION".into()));
 
     if let Some(ref extname) = n.extname {
         e.space();
-        e.token(TokenKind::IDENT(extname.sval.clone()));
+        e.token(TokenKind::IDENT(extname.sval.clone().into()));
     }
 
     e.token(TokenKind::SEMICOLON);
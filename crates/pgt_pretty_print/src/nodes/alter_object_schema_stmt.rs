@@ -31,7 +31,7 @@ pub(super) fn emit_alter_object_schema_stmt(e: &mut EventEmitter, n: &AlterObjec
         _ => "UNKNOWN",
     };
 
-    e.token(TokenKind::IDENT(object_type_str.to_string()));
+    e.token(TokenKind::KEYWORD(object_type_str.into()));
     e.space();
 
     if n.missing_ok {
@@ -53,9 +53,9 @@ pub(super) fn emit_alter_object_schema_stmt(e: &mut EventEmitter, n: &AlterObjec
         e.space();
         e.token(TokenKind::SET_KW);
         e.space();
-        e.token(TokenKind::IDENT("SCHEMA".to_string()));
+        e.token(TokenKind::KEYWORD("SCHEMA".into()));
         e.space();
-        e.token(TokenKind::IDENT(n.newschema.clone()));
+        e.token(TokenKind::IDENT(n.newschema.clone().into()));
     }
 
     e.token(TokenKind::SEMICOLON);
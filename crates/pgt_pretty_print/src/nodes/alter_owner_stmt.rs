@@ -16,97 +16,97 @@ pub(super) fn emit_alter_owner_stmt(e: &mut EventEmitter, n: &AlterOwnerStmt) {
     match n.object_type {
         1 => {
             // ObjectAccessMethod
-            e.token(TokenKind::IDENT("ACCESS".to_string()));
+            e.token(TokenKind::KEYWORD("ACCESS".into()));
             e.space();
-            e.token(TokenKind::IDENT("METHOD".to_string()));
+            e.token(TokenKind::KEYWORD("METHOD".into()));
         }
-        2 => e.token(TokenKind::IDENT("AGGREGATE".to_string())),
-        8 => e.token(TokenKind::IDENT("COLLATION".to_string())),
-        9 => e.token(TokenKind::IDENT("CONVERSION".to_string())),
+        2 => e.token(TokenKind::KEYWORD("AGGREGATE".into())),
+        8 => e.token(TokenKind::KEYWORD("COLLATION".into())),
+        9 => e.token(TokenKind::KEYWORD("CONVERSION".into())),
         10 => e.token(TokenKind::DATABASE_KW),
         13 => e.token(TokenKind::DOMAIN_KW),
         15 => {
             // ObjectEventTrigger
-            e.token(TokenKind::IDENT("EVENT".to_string()));
+            e.token(TokenKind::KEYWORD("EVENT".into()));
             e.space();
-            e.token(TokenKind::IDENT("TRIGGER".to_string()));
+            e.token(TokenKind::KEYWORD("TRIGGER".into()));
         }
         17 => {
             // ObjectFdw
-            e.token(TokenKind::IDENT("FOREIGN".to_string()));
+            e.token(TokenKind::KEYWORD("FOREIGN".into()));
             e.space();
-            e.token(TokenKind::IDENT("DATA".to_string()));
+            e.token(TokenKind::KEYWORD("DATA".into()));
             e.space();
-            e.token(TokenKind::IDENT("WRAPPER".to_string()));
+            e.token(TokenKind::KEYWORD("WRAPPER".into()));
         }
         18 => {
             // ObjectForeignServer
-            e.token(TokenKind::IDENT("SERVER".to_string()));
+            e.token(TokenKind::KEYWORD("SERVER".into()));
         }
         19 => {
             // ObjectForeignTable
-            e.token(TokenKind::IDENT("FOREIGN".to_string()));
+            e.token(TokenKind::KEYWORD("FOREIGN".into()));
             e.space();
             e.token(TokenKind::TABLE_KW);
         }
         20 => e.token(TokenKind::FUNCTION_KW),
-        22 => e.token(TokenKind::IDENT("LANGUAGE".to_string())),
+        22 => e.token(TokenKind::KEYWORD("LANGUAGE".into())),
         23 => {
             // ObjectLargeobject
-            e.token(TokenKind::IDENT("LARGE".to_string()));
+            e.token(TokenKind::KEYWORD("LARGE".into()));
             e.space();
-            e.token(TokenKind::IDENT("OBJECT".to_string()));
+            e.token(TokenKind::KEYWORD("OBJECT".into()));
         }
         24 => {
             // ObjectMatview
-            e.token(TokenKind::IDENT("MATERIALIZED".to_string()));
+            e.token(TokenKind::KEYWORD("MATERIALIZED".into()));
             e.space();
             e.token(TokenKind::VIEW_KW);
         }
         25 => {
             // ObjectOpclass
-            e.token(TokenKind::IDENT("OPERATOR".to_string()));
+            e.token(TokenKind::KEYWORD("OPERATOR".into()));
             e.space();
-            e.token(TokenKind::IDENT("CLASS".to_string()));
+            e.token(TokenKind::KEYWORD("CLASS".into()));
         }
-        26 => e.token(TokenKind::IDENT("OPERATOR".to_string())),
+        26 => e.token(TokenKind::KEYWORD("OPERATOR".into())),
         27 => {
             // ObjectOpfamily
-            e.token(TokenKind::IDENT("OPERATOR".to_string()));
+            e.token(TokenKind::KEYWORD("OPERATOR".into()));
             e.space();
-            e.token(TokenKind::IDENT("FAMILY".to_string()));
+            e.token(TokenKind::KEYWORD("FAMILY".into()));
         }
-        30 => e.token(TokenKind::IDENT("PROCEDURE".to_string())),
-        31 => e.token(TokenKind::IDENT("PUBLICATION".to_string())),
-        35 => e.token(TokenKind::IDENT("ROUTINE".to_string())),
+        30 => e.token(TokenKind::KEYWORD("PROCEDURE".into())),
+        31 => e.token(TokenKind::KEYWORD("PUBLICATION".into())),
+        35 => e.token(TokenKind::KEYWORD("ROUTINE".into())),
         37 => e.token(TokenKind::SCHEMA_KW),
         38 => e.token(TokenKind::SEQUENCE_KW),
-        39 => e.token(TokenKind::IDENT("SUBSCRIPTION".to_string())),
+        39 => e.token(TokenKind::KEYWORD("SUBSCRIPTION".into())),
         40 => {
             // ObjectStatisticExt
-            e.token(TokenKind::IDENT("STATISTICS".to_string()));
+            e.token(TokenKind::KEYWORD("STATISTICS".into()));
         }
         42 => e.token(TokenKind::TABLE_KW),
-        43 => e.token(TokenKind::IDENT("TABLESPACE".to_string())),
+        43 => e.token(TokenKind::KEYWORD("TABLESPACE".into())),
         46 => {
             // ObjectTsconfiguration
-            e.token(TokenKind::IDENT("TEXT".to_string()));
+            e.token(TokenKind::KEYWORD("TEXT".into()));
             e.space();
-            e.token(TokenKind::IDENT("SEARCH".to_string()));
+            e.token(TokenKind::KEYWORD("SEARCH".into()));
             e.space();
-            e.token(TokenKind::IDENT("CONFIGURATION".to_string()));
+            e.token(TokenKind::KEYWORD("CONFIGURATION".into()));
         }
         47 => {
             // ObjectTsdictionary
-            e.token(TokenKind::IDENT("TEXT".to_string()));
+            e.token(TokenKind::KEYWORD("TEXT".into()));
             e.space();
-            e.token(TokenKind::IDENT("SEARCH".to_string()));
+            e.token(TokenKind::KEYWORD("SEARCH".into()));
             e.space();
-            e.token(TokenKind::IDENT("DICTIONARY".to_string()));
+            e.token(TokenKind::KEYWORD("DICTIONARY".into()));
         }
         50 => e.token(TokenKind::TYPE_KW),
         52 => e.token(TokenKind::VIEW_KW),
-        _ => e.token(TokenKind::IDENT("OBJECT".to_string())), // Fallback for unsupported types
+        _ => e.token(TokenKind::KEYWORD("OBJECT".into())), // Fallback for unsupported types
     }
 
     e.space();
@@ -118,7 +118,7 @@ pub(super) fn emit_alter_owner_stmt(e: &mut EventEmitter, n: &AlterOwnerStmt) {
 
     // OWNER TO
     e.space();
-    e.token(TokenKind::IDENT("OWNER".to_string()));
+    e.token(TokenKind::KEYWORD("OWNER".into()));
     e.space();
     e.token(TokenKind::TO_KW);
 
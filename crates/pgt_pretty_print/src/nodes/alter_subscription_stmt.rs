@@ -1,77 +1,134 @@
+use pgt_query::protobuf::{AlterSubscriptionStmt, AlterSubscriptionType, DefElem};
+
 use super::node_list::emit_comma_separated_list;
 use crate::{
     TokenKind,
     emitter::{EventEmitter, GroupKind},
 };
-use pgt_query::protobuf::AlterSubscriptionStmt;
 
 pub(super) fn emit_alter_subscription_stmt(e: &mut EventEmitter, n: &AlterSubscriptionStmt) {
     e.group_start(GroupKind::AlterSubscriptionStmt);
 
     e.token(TokenKind::ALTER_KW);
     e.space();
-    e.token(TokenKind::IDENT("SUBSCRIPTION".to_string()));
+    e.token(TokenKind::KEYWORD("SUBSCRIPTION".into()));
     e.space();
-    e.token(TokenKind::IDENT(n.subname.clone()));
+    e.token(TokenKind::IDENT(n.subname.clone().into()));
 
     e.space();
 
-    // Kind enum: 0=Undefined, 1=OPTIONS, 2=CONNECTION, 3=SET_PUBLICATION, 4=ADD_PUBLICATION, 5=DROP_PUBLICATION, 6=REFRESH, 7=ENABLED, 8=SKIP
-    match n.kind {
-        1 => {
-            // OPTIONS - handled via options field below
+    let kind = AlterSubscriptionType::try_from(n.kind).unwrap_or(AlterSubscriptionType::Undefined);
+
+    match kind {
+        AlterSubscriptionType::AlterSubscriptionOptions => {
+            emit_with_options(e, n, TokenKind::SET_KW)
         }
-        2 => {
-            e.token(TokenKind::IDENT("CONNECTION".to_string()));
+        AlterSubscriptionType::AlterSubscriptionConnection => {
+            e.token(TokenKind::KEYWORD("CONNECTION".into()));
             e.space();
-            e.token(TokenKind::IDENT(format!("'{}'", n.conninfo)));
+            let escaped = n.conninfo.replace('\'', "''");
+            e.token(TokenKind::IDENT(format!("'{}'", escaped).into()));
         }
-        3 => {
+        AlterSubscriptionType::AlterSubscriptionSetPublication => {
             e.token(TokenKind::SET_KW);
             e.space();
-            e.token(TokenKind::IDENT("PUBLICATION".to_string()));
+            e.token(TokenKind::KEYWORD("PUBLICATION".into()));
             e.space();
-            emit_comma_separated_list(e, &n.publication, super::emit_node);
+            super::emit_identifier_list(e, &n.publication);
+            emit_with_options(e, n, TokenKind::WITH_KW);
         }
-        4 => {
-            e.token(TokenKind::IDENT("ADD".to_string()));
+        AlterSubscriptionType::AlterSubscriptionAddPublication => {
+            e.token(TokenKind::KEYWORD("ADD".into()));
             e.space();
-            e.token(TokenKind::IDENT("PUBLICATION".to_string()));
+            e.token(TokenKind::KEYWORD("PUBLICATION".into()));
             e.space();
-            emit_comma_separated_list(e, &n.publication, super::emit_node);
+            super::emit_identifier_list(e, &n.publication);
+            emit_with_options(e, n, TokenKind::WITH_KW);
         }
-        5 => {
+        AlterSubscriptionType::AlterSubscriptionDropPublication => {
             e.token(TokenKind::DROP_KW);
             e.space();
-            e.token(TokenKind::IDENT("PUBLICATION".to_string()));
+            e.token(TokenKind::KEYWORD("PUBLICATION".into()));
             e.space();
-            emit_comma_separated_list(e, &n.publication, super::emit_node);
+            super::emit_identifier_list(e, &n.publication);
+            emit_with_options(e, n, TokenKind::WITH_KW);
         }
-        6 => {
-            e.token(TokenKind::IDENT("REFRESH".to_string()));
+        AlterSubscriptionType::AlterSubscriptionRefresh => {
+            e.token(TokenKind::KEYWORD("REFRESH".into()));
             e.space();
-            e.token(TokenKind::IDENT("PUBLICATION".to_string()));
+            e.token(TokenKind::KEYWORD("PUBLICATION".into()));
+            emit_with_options(e, n, TokenKind::WITH_KW);
         }
-        7 => {
-            e.token(TokenKind::IDENT("ENABLE".to_string()));
+        AlterSubscriptionType::AlterSubscriptionEnabled => {
+            e.token(TokenKind::KEYWORD(enable_keyword(n).into()));
         }
-        8 => {
-            e.token(TokenKind::IDENT("SKIP".to_string()));
+        AlterSubscriptionType::AlterSubscriptionSkip => {
+            e.token(TokenKind::KEYWORD("SKIP".into()));
+            e.space();
+            e.token(TokenKind::L_PAREN);
+            emit_comma_separated_list(e, &n.options, |node, e| {
+                let def_elem = assert_node_variant!(DefElem, node);
+                emit_skip_def_elem(e, def_elem);
+            });
+            e.token(TokenKind::R_PAREN);
         }
-        _ => {}
-    }
-
-    // Options
-    if !n.options.is_empty() {
-        e.space();
-        e.token(TokenKind::WITH_KW);
-        e.space();
-        e.token(TokenKind::L_PAREN);
-        emit_comma_separated_list(e, &n.options, super::emit_node);
-        e.token(TokenKind::R_PAREN);
+        other => panic!("emit_alter_subscription_stmt: unhandled AlterSubscriptionType {other:?}"),
     }
 
     e.token(TokenKind::SEMICOLON);
 
     e.group_end();
 }
+
+/// Whether `ENABLE` or `DISABLE` was written, recovered from the `enabled`
+/// boolean carried in `options` (`kind` alone can't distinguish the two -
+/// both parse to `ALTER_SUBSCRIPTION_ENABLED`).
+fn enable_keyword(n: &AlterSubscriptionStmt) -> &'static str {
+    for opt in &n.options {
+        let Some(pgt_query::NodeEnum::DefElem(def_elem)) = opt.node.as_ref() else {
+            continue;
+        };
+        if def_elem.defname == "enabled" {
+            if let Some(pgt_query::NodeEnum::Boolean(b)) =
+                def_elem.arg.as_ref().and_then(|arg| arg.node.as_ref())
+            {
+                return if b.boolval { "ENABLE" } else { "DISABLE" };
+            }
+        }
+    }
+    "ENABLE"
+}
+
+/// `name = 'value'` - the form `SKIP`'s options use, distinct from the
+/// `name 'value'` of [`super::emit_options_def_elem`].
+fn emit_skip_def_elem(e: &mut EventEmitter, n: &DefElem) {
+    e.token(TokenKind::IDENT(n.defname.clone().into()));
+    e.space();
+    e.token(TokenKind::IDENT("=".into()));
+    e.space();
+    if let Some(pgt_query::NodeEnum::String(s)) = n.arg.as_ref().and_then(|arg| arg.node.as_ref())
+    {
+        let escaped = s.sval.replace('\'', "''");
+        e.token(TokenKind::IDENT(format!("'{}'", escaped).into()));
+    }
+}
+
+/// `<keyword> (name 'value', ...)` - shared by every kind whose options
+/// render as a parenthesized clause (`SET (...)` for a bare `OPTIONS`
+/// change, `WITH (...)` trailing `SET`/`ADD`/`DROP PUBLICATION` and
+/// `REFRESH PUBLICATION`). `SKIP` does not use this - it has its own
+/// `name = value` formatting and no leading keyword.
+fn emit_with_options(e: &mut EventEmitter, n: &AlterSubscriptionStmt, keyword: TokenKind) {
+    if n.options.is_empty() {
+        return;
+    }
+    e.space();
+    e.token(keyword);
+    e.space();
+    e.token(TokenKind::L_PAREN);
+    emit_comma_separated_list(e, &n.options, |node, e| {
+        let def_elem = assert_node_variant!(DefElem, node);
+        super::emit_options_def_elem(e, def_elem);
+    });
+    e.token(TokenKind::R_PAREN);
+}
@@ -3,24 +3,34 @@ use pgt_query::protobuf::{BoolExpr, BoolExprType};
 use crate::{
     TokenKind,
     emitter::{EventEmitter, GroupKind},
-    nodes::node_list::emit_keyword_separated_list,
+    nodes::{
+        node_list::emit_keyword_separated_list,
+        precedence::{Precedence, Side, emit_operand},
+    },
 };
 
 pub(super) fn emit_bool_expr(e: &mut EventEmitter, n: &BoolExpr) {
     e.group_start(GroupKind::BoolExpr);
 
     match n.boolop() {
-        BoolExprType::AndExpr => emit_keyword_separated_list(e, &n.args, TokenKind::AND_KW),
-        BoolExprType::OrExpr => emit_keyword_separated_list(e, &n.args, TokenKind::OR_KW),
+        BoolExprType::AndExpr => {
+            emit_keyword_separated_list(e, &n.args, TokenKind::AND_KW, |arg, e| {
+                emit_operand(e, arg, Precedence::And, Side::Left)
+            })
+        }
+        BoolExprType::OrExpr => {
+            emit_keyword_separated_list(e, &n.args, TokenKind::OR_KW, |arg, e| {
+                emit_operand(e, arg, Precedence::Or, Side::Left)
+            })
+        }
         BoolExprType::NotExpr => {
-            e.token(crate::TokenKind::NOT_KW);
+            e.token(TokenKind::NOT_KW);
             e.space();
             assert!(
                 n.args.len() == 1,
                 "NOT expressions should have exactly one argument"
             );
-            let arg = &n.args[0];
-            super::emit_node(arg, e);
+            emit_operand(e, &n.args[0], Precedence::Not, Side::Right);
         }
         BoolExprType::Undefined => unreachable!("Undefined BoolExprType"),
     }
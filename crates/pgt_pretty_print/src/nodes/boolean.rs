@@ -7,8 +7,7 @@ use crate::{
 
 pub(super) fn emit_boolean(e: &mut EventEmitter, n: &Boolean) {
     e.group_start(GroupKind::Boolean);
-    // todo: user needs to be able to configure the case of boolean literals
     let val_str = if n.boolval { "TRUE" } else { "FALSE" };
-    e.token(TokenKind::IDENT(val_str.to_string()));
+    e.token(TokenKind::KEYWORD(val_str.into()));
     e.group_end();
 }
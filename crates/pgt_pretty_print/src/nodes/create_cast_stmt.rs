@@ -41,7 +41,7 @@ pub(super) fn emit_create_cast_stmt(e: &mut EventEmitter, n: &CreateCastStmt) {
         e.space();
         e.token(TokenKind::WITH_KW);
         e.space();
-        e.token(TokenKind::IDENT("INOUT".to_string()));
+        e.token(TokenKind::KEYWORD("INOUT".into()));
     } else {
         e.space();
         e.token(TokenKind::WITHOUT_KW);
@@ -54,12 +54,12 @@ pub(super) fn emit_create_cast_stmt(e: &mut EventEmitter, n: &CreateCastStmt) {
         e.space();
         e.token(TokenKind::AS_KW);
         e.space();
-        e.token(TokenKind::IDENT("IMPLICIT".to_string()));
+        e.token(TokenKind::KEYWORD("IMPLICIT".into()));
     } else if n.context == 1 {
         e.space();
         e.token(TokenKind::AS_KW);
         e.space();
-        e.token(TokenKind::IDENT("ASSIGNMENT".to_string()));
+        e.token(TokenKind::KEYWORD("ASSIGNMENT".into()));
     }
 
     e.token(TokenKind::SEMICOLON);
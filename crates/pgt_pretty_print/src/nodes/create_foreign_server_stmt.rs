@@ -11,7 +11,7 @@ pub(super) fn emit_create_foreign_server_stmt(e: &mut EventEmitter, n: &CreateFo
 
     e.token(TokenKind::CREATE_KW);
     e.space();
-    e.token(TokenKind::IDENT("SERVER".to_string()));
+    e.token(TokenKind::KEYWORD("SERVER".into()));
 
     // Emit IF NOT EXISTS if present
     if n.if_not_exists {
@@ -25,7 +25,7 @@ pub(super) fn emit_create_foreign_server_stmt(e: &mut EventEmitter, n: &CreateFo
 
     // Emit server name
     e.space();
-    e.token(TokenKind::IDENT(n.servername.clone()));
+    e.token(TokenKind::IDENT(n.servername.clone().into()));
 
     // Emit TYPE if present
     if !n.servertype.is_empty() {
@@ -33,7 +33,7 @@ pub(super) fn emit_create_foreign_server_stmt(e: &mut EventEmitter, n: &CreateFo
         e.indent_start();
         e.token(TokenKind::TYPE_KW);
         e.space();
-        e.token(TokenKind::IDENT(format!("'{}'", n.servertype)));
+        e.token(TokenKind::IDENT(format!("'{}'", n.servertype).into()));
         e.indent_end();
     }
 
@@ -41,29 +41,29 @@ pub(super) fn emit_create_foreign_server_stmt(e: &mut EventEmitter, n: &CreateFo
     if !n.version.is_empty() {
         e.line(LineType::SoftOrSpace);
         e.indent_start();
-        e.token(TokenKind::IDENT("VERSION".to_string()));
+        e.token(TokenKind::KEYWORD("VERSION".into()));
         e.space();
-        e.token(TokenKind::IDENT(format!("'{}'", n.version)));
+        e.token(TokenKind::IDENT(format!("'{}'", n.version).into()));
         e.indent_end();
     }
 
     // Emit FOREIGN DATA WRAPPER
     e.line(LineType::SoftOrSpace);
     e.indent_start();
-    e.token(TokenKind::IDENT("FOREIGN".to_string()));
+    e.token(TokenKind::KEYWORD("FOREIGN".into()));
     e.space();
-    e.token(TokenKind::IDENT("DATA".to_string()));
+    e.token(TokenKind::KEYWORD("DATA".into()));
     e.space();
-    e.token(TokenKind::IDENT("WRAPPER".to_string()));
+    e.token(TokenKind::KEYWORD("WRAPPER".into()));
     e.space();
-    e.token(TokenKind::IDENT(n.fdwname.clone()));
+    e.token(TokenKind::IDENT(n.fdwname.clone().into()));
     e.indent_end();
 
     // Emit OPTIONS if present
     if !n.options.is_empty() {
         e.line(LineType::SoftOrSpace);
         e.indent_start();
-        e.token(TokenKind::IDENT("OPTIONS".to_string()));
+        e.token(TokenKind::KEYWORD("OPTIONS".into()));
         e.space();
         e.token(TokenKind::L_PAREN);
         emit_comma_separated_list(e, &n.options, |n, e| {
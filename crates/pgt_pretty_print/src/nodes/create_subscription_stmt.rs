@@ -3,38 +3,44 @@ use crate::{
     emitter::{EventEmitter, GroupKind},
     nodes::node_list::emit_comma_separated_list,
 };
-use pgt_query::{NodeEnum, protobuf::CreateSubscriptionStmt};
+use pgt_query::protobuf::{CreateSubscriptionStmt, DefElem};
 
 pub(super) fn emit_create_subscription_stmt(e: &mut EventEmitter, n: &CreateSubscriptionStmt) {
     e.group_start(GroupKind::CreateSubscriptionStmt);
 
     e.token(TokenKind::CREATE_KW);
     e.space();
-    e.token(TokenKind::IDENT("SUBSCRIPTION".to_string()));
+    e.token(TokenKind::KEYWORD("SUBSCRIPTION".into()));
     e.space();
-    e.token(TokenKind::IDENT(n.subname.clone()));
+    e.token(TokenKind::IDENT(n.subname.clone().into()));
 
-    e.space();
-    e.token(TokenKind::IDENT("CONNECTION".to_string()));
-    e.space();
-    // Emit connection string as string literal
-    e.token(TokenKind::IDENT(format!("'{}'", n.conninfo)));
+    // Both CONNECTION and PUBLICATION are optional: `CREATE SUBSCRIPTION name`
+    // on its own creates a disabled, disconnected subscription to be filled
+    // in later via `ALTER SUBSCRIPTION ... CONNECTION`/`SET PUBLICATION`.
+    if !n.conninfo.is_empty() {
+        e.space();
+        e.token(TokenKind::KEYWORD("CONNECTION".into()));
+        e.space();
+        let escaped = n.conninfo.replace('\'', "''");
+        e.token(TokenKind::IDENT(format!("'{}'", escaped).into()));
+    }
 
-    e.space();
-    e.token(TokenKind::IDENT("PUBLICATION".to_string()));
-    e.space();
-    emit_comma_separated_list(e, &n.publication, |node, e| {
-        if let Some(NodeEnum::String(s)) = &node.node {
-            e.token(TokenKind::IDENT(s.sval.clone()));
-        }
-    });
+    if !n.publication.is_empty() {
+        e.space();
+        e.token(TokenKind::KEYWORD("PUBLICATION".into()));
+        e.space();
+        super::emit_identifier_list(e, &n.publication);
+    }
 
     if !n.options.is_empty() {
         e.space();
         e.token(TokenKind::WITH_KW);
         e.space();
         e.token(TokenKind::L_PAREN);
-        emit_comma_separated_list(e, &n.options, super::emit_node);
+        emit_comma_separated_list(e, &n.options, |node, e| {
+            let def_elem = assert_node_variant!(DefElem, node);
+            super::emit_options_def_elem(e, def_elem);
+        });
         e.token(TokenKind::R_PAREN);
     }
 
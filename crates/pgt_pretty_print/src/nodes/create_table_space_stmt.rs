@@ -14,24 +14,24 @@ pub(super) fn emit_create_table_space_stmt(e: &mut EventEmitter, n: &CreateTable
 
     if !n.tablespacename.is_empty() {
         e.space();
-        e.token(TokenKind::IDENT(n.tablespacename.clone()));
+        e.token(TokenKind::IDENT(n.tablespacename.clone().into()));
     }
 
     // OWNER
     if let Some(ref owner) = n.owner {
         e.space();
-        e.token(TokenKind::IDENT("OWNER".to_string()));
+        e.token(TokenKind::KEYWORD("OWNER".into()));
         e.space();
         super::emit_role_spec(e, owner);
     }
 
     // LOCATION (always required in CREATE TABLESPACE, even if empty string)
     e.space();
-    e.token(TokenKind::IDENT("LOCATION".to_string()));
+    e.token(TokenKind::KEYWORD("LOCATION".into()));
     e.space();
     // Emit location as a string literal with proper escaping
     let escaped_location = n.location.replace('\'', "''");
-    e.token(TokenKind::IDENT(format!("'{}'", escaped_location)));
+    e.token(TokenKind::IDENT(format!("'{}'", escaped_location).into()));
 
     // WITH options
     if !n.options.is_empty() {
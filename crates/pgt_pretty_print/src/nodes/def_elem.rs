@@ -0,0 +1,24 @@
+use pgt_query::protobuf::DefElem;
+
+use crate::TokenKind;
+use crate::emitter::EventEmitter;
+
+/// Emit a `DefElem` as an `OPTIONS (...)` entry: `name 'value'`.
+pub(super) fn emit_options_def_elem(e: &mut EventEmitter, n: &DefElem) {
+    if n.location >= 0 {
+        let span = (n.location as usize)..(n.location as usize + n.defname.len());
+        e.token_at(TokenKind::IDENT(n.defname.clone().into()), span);
+    } else {
+        e.token(TokenKind::IDENT(n.defname.clone().into()));
+    }
+
+    if let Some(ref arg) = n.arg {
+        e.space();
+        if let Some(pgt_query::NodeEnum::String(s)) = arg.node.as_ref() {
+            let escaped = s.sval.replace('\'', "''");
+            e.token(TokenKind::IDENT(format!("'{}'", escaped).into()));
+        } else {
+            super::emit_node(arg, e);
+        }
+    }
+}
@@ -64,34 +64,34 @@ pub(super) fn emit_define_stmt(e: &mut EventEmitter, n: &DefineStmt) {
         ObjectType::ObjectType => e.token(TokenKind::TYPE_KW),
         ObjectType::ObjectCollation => e.token(TokenKind::COLLATION_KW),
         ObjectType::ObjectTsdictionary => {
-            e.token(TokenKind::IDENT("TEXT".to_string()));
+            e.token(TokenKind::KEYWORD("TEXT".into()));
             e.space();
-            e.token(TokenKind::IDENT("SEARCH".to_string()));
+            e.token(TokenKind::KEYWORD("SEARCH".into()));
             e.space();
-            e.token(TokenKind::IDENT("DICTIONARY".to_string()));
+            e.token(TokenKind::KEYWORD("DICTIONARY".into()));
         }
         ObjectType::ObjectTsconfiguration => {
-            e.token(TokenKind::IDENT("TEXT".to_string()));
+            e.token(TokenKind::KEYWORD("TEXT".into()));
             e.space();
-            e.token(TokenKind::IDENT("SEARCH".to_string()));
+            e.token(TokenKind::KEYWORD("SEARCH".into()));
             e.space();
-            e.token(TokenKind::IDENT("CONFIGURATION".to_string()));
+            e.token(TokenKind::KEYWORD("CONFIGURATION".into()));
         }
         ObjectType::ObjectTsparser => {
-            e.token(TokenKind::IDENT("TEXT".to_string()));
+            e.token(TokenKind::KEYWORD("TEXT".into()));
             e.space();
-            e.token(TokenKind::IDENT("SEARCH".to_string()));
+            e.token(TokenKind::KEYWORD("SEARCH".into()));
             e.space();
-            e.token(TokenKind::IDENT("PARSER".to_string()));
+            e.token(TokenKind::KEYWORD("PARSER".into()));
         }
         ObjectType::ObjectTstemplate => {
-            e.token(TokenKind::IDENT("TEXT".to_string()));
+            e.token(TokenKind::KEYWORD("TEXT".into()));
             e.space();
-            e.token(TokenKind::IDENT("SEARCH".to_string()));
+            e.token(TokenKind::KEYWORD("SEARCH".into()));
             e.space();
-            e.token(TokenKind::IDENT("TEMPLATE".to_string()));
+            e.token(TokenKind::KEYWORD("TEMPLATE".into()));
         }
-        _ => e.token(TokenKind::IDENT(format!("{:?}", kind))),
+        _ => e.token(TokenKind::IDENT(format!("{:?}", kind).into())),
     }
 
     if n.if_not_exists {
@@ -1,18 +1,24 @@
 use crate::{
     TokenKind,
-    emitter::{EventEmitter, GroupKind},
+    emitter::{EventEmitter, GroupKind, SemicolonPolicy},
 };
 use pgt_query::protobuf::DeleteStmt;
 
+use super::node_list::emit_comma_separated_list;
+use super::res_target::emit_returning_list;
+
 pub(super) fn emit_delete_stmt(e: &mut EventEmitter, n: &DeleteStmt) {
     emit_delete_stmt_impl(e, n, true);
 }
 
+/// Used when a `DeleteStmt` is nested inside another statement (a CTE body,
+/// ...) - a semicolon is never syntactically valid there, regardless of
+/// [`SemicolonPolicy`].
 pub(super) fn emit_delete_stmt_no_semicolon(e: &mut EventEmitter, n: &DeleteStmt) {
     emit_delete_stmt_impl(e, n, false);
 }
 
-fn emit_delete_stmt_impl(e: &mut EventEmitter, n: &DeleteStmt, with_semicolon: bool) {
+fn emit_delete_stmt_impl(e: &mut EventEmitter, n: &DeleteStmt, semicolon_eligible: bool) {
     e.group_start(GroupKind::DeleteStmt);
 
     e.token(TokenKind::DELETE_KW);
@@ -25,6 +31,14 @@ fn emit_delete_stmt_impl(e: &mut EventEmitter, n: &DeleteStmt, with_semicolon: b
         super::emit_range_var(e, relation);
     }
 
+    // Emit USING clause
+    if !n.using_clause.is_empty() {
+        e.space();
+        e.token(TokenKind::USING_KW);
+        e.space();
+        emit_comma_separated_list(e, &n.using_clause, |node, e| super::emit_node(node, e));
+    }
+
     // Emit WHERE clause
     if let Some(ref where_clause) = n.where_clause {
         e.space();
@@ -33,10 +47,9 @@ fn emit_delete_stmt_impl(e: &mut EventEmitter, n: &DeleteStmt, with_semicolon: b
         super::emit_node(where_clause, e);
     }
 
-    // TODO: Handle USING clause
-    // TODO: Handle RETURNING clause
+    emit_returning_list(e, &n.returning_list);
 
-    if with_semicolon {
+    if semicolon_eligible && e.config().semicolon_policy == SemicolonPolicy::Always {
         e.token(TokenKind::SEMICOLON);
     }
 
@@ -17,9 +17,9 @@ pub(super) fn emit_do_stmt(e: &mut EventEmitter, n: &DoStmt) {
                 if let Some(lang_node) = &def_elem.arg {
                     if let Some(NodeEnum::String(s)) = &lang_node.node {
                         e.space();
-                        e.token(TokenKind::IDENT("LANGUAGE".to_string()));
+                        e.token(TokenKind::KEYWORD("LANGUAGE".into()));
                         e.space();
-                        e.token(TokenKind::IDENT(s.sval.clone()));
+                        e.token(TokenKind::IDENT(s.sval.clone().into()));
                     }
                 }
             }
@@ -34,9 +34,11 @@ pub(super) fn emit_do_stmt(e: &mut EventEmitter, n: &DoStmt) {
                 if let Some(code_node) = &def_elem.arg {
                     if let Some(NodeEnum::String(s)) = &code_node.node {
                         e.space();
-                        e.token(TokenKind::IDENT("$$".to_string()));
-                        e.token(TokenKind::IDENT(s.sval.clone()));
-                        e.token(TokenKind::IDENT("$$".to_string()));
+                        e.token(TokenKind::IDENT("$$".into()));
+                        e.no_space();
+                        e.token(TokenKind::IDENT(s.sval.clone().into()));
+                        e.no_space();
+                        e.token(TokenKind::IDENT("$$".into()));
                     }
                 }
             }
@@ -1,23 +1,32 @@
 use crate::{
     TokenKind,
-    emitter::{EventEmitter, GroupKind, LineType},
+    emitter::{EventEmitter, GroupKind, LineType, SemicolonPolicy},
 };
 use pgt_query::protobuf::InsertStmt;
 
 use super::node_list::emit_comma_separated_list;
-use super::res_target::emit_column_name;
+use super::res_target::{emit_column_name, emit_returning_list};
 
 pub(super) fn emit_insert_stmt(e: &mut EventEmitter, n: &InsertStmt) {
     emit_insert_stmt_impl(e, n, true);
 }
 
+/// Used when an `InsertStmt` is nested inside another statement (a CTE body,
+/// ...) - a semicolon is never syntactically valid there, regardless of
+/// [`SemicolonPolicy`].
 pub(super) fn emit_insert_stmt_no_semicolon(e: &mut EventEmitter, n: &InsertStmt) {
     emit_insert_stmt_impl(e, n, false);
 }
 
-fn emit_insert_stmt_impl(e: &mut EventEmitter, n: &InsertStmt, with_semicolon: bool) {
+fn emit_insert_stmt_impl(e: &mut EventEmitter, n: &InsertStmt, semicolon_eligible: bool) {
     e.group_start(GroupKind::InsertStmt);
 
+    // Emit WITH clause (Common Table Expressions) if present
+    if let Some(ref with_clause) = n.with_clause {
+        super::emit_with_clause(e, with_clause);
+        e.line(LineType::SoftOrSpace);
+    }
+
     e.token(TokenKind::INSERT_KW);
     e.space();
     e.token(TokenKind::INTO_KW);
@@ -64,10 +73,9 @@ fn emit_insert_stmt_impl(e: &mut EventEmitter, n: &InsertStmt, with_semicolon: b
         super::emit_on_conflict_clause(e, on_conflict);
     }
 
-    // TODO: Handle RETURNING clause
-    // TODO: Handle WITH clause (CTEs)
+    emit_returning_list(e, &n.returning_list);
 
-    if with_semicolon {
+    if semicolon_eligible && e.config().semicolon_policy == SemicolonPolicy::Always {
         e.token(TokenKind::SEMICOLON);
     }
 
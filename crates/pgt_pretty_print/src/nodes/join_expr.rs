@@ -4,7 +4,7 @@ use crate::TokenKind;
 use crate::emitter::{EventEmitter, GroupKind};
 
 use super::node_list::emit_comma_separated_list;
-use super::string::emit_identifier;
+use super::string::emit_identifier_maybe_quoted;
 
 pub(super) fn emit_join_expr(e: &mut EventEmitter, n: &JoinExpr) {
     e.group_start(GroupKind::JoinExpr);
@@ -54,17 +54,17 @@ pub(super) fn emit_join_expr(e: &mut EventEmitter, n: &JoinExpr) {
         }
         x if x == JoinType::JoinSemi as i32 => {
             e.space();
-            e.token(TokenKind::IDENT("SEMI".to_string()));
+            e.token(TokenKind::KEYWORD("SEMI".into()));
         }
         x if x == JoinType::JoinAnti as i32 => {
             e.space();
-            e.token(TokenKind::IDENT("ANTI".to_string()));
+            e.token(TokenKind::KEYWORD("ANTI".into()));
         }
         x if x == JoinType::JoinRightAnti as i32 => {
             e.space();
             e.token(TokenKind::RIGHT_KW);
             e.space();
-            e.token(TokenKind::IDENT("ANTI".to_string()));
+            e.token(TokenKind::KEYWORD("ANTI".into()));
         }
         _ => {
             // CROSS JOIN or other types
@@ -91,7 +91,7 @@ pub(super) fn emit_join_expr(e: &mut EventEmitter, n: &JoinExpr) {
         emit_comma_separated_list(e, &n.using_clause, |node, e| {
             // For USING clause, String nodes should be identifiers
             if let Some(pgt_query::NodeEnum::String(s)) = node.node.as_ref() {
-                emit_identifier(e, &s.sval);
+                emit_identifier_maybe_quoted(e, &s.sval);
             } else {
                 super::emit_node(node, e);
             }
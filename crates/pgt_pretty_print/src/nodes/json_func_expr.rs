@@ -0,0 +1,228 @@
+use std::borrow::Cow;
+
+use crate::{
+    TokenKind,
+    emitter::{EventEmitter, GroupKind},
+};
+use pgt_query::protobuf::{JsonBehavior, JsonFuncExpr};
+
+use super::node_list::emit_comma_separated_list;
+
+// JsonExprOp, as pgt_query's protobuf numbers it: PostgreSQL's enum order in
+// primnodes.h, shifted up by one for the leading `Undefined = 0`.
+const JSON_EXISTS_OP: i32 = 1;
+const JSON_QUERY_OP: i32 = 2;
+const JSON_VALUE_OP: i32 = 3;
+
+// JsonBehaviorType, matching json_table.rs's `JsonBehavior` handling.
+const JSON_BEHAVIOR_NULL: i32 = 1;
+const JSON_BEHAVIOR_ERROR: i32 = 2;
+const JSON_BEHAVIOR_EMPTY: i32 = 3;
+const JSON_BEHAVIOR_TRUE: i32 = 4;
+const JSON_BEHAVIOR_FALSE: i32 = 5;
+const JSON_BEHAVIOR_UNKNOWN: i32 = 6;
+const JSON_BEHAVIOR_EMPTY_ARRAY: i32 = 7;
+const JSON_BEHAVIOR_EMPTY_OBJECT: i32 = 8;
+const JSON_BEHAVIOR_DEFAULT: i32 = 9;
+
+// JsonWrapper, matching json_table.rs's `JsonTableColumn.wrapper` handling.
+const JSW_NONE: i32 = 2;
+const JSW_CONDITIONAL: i32 = 3;
+const JSW_UNCONDITIONAL: i32 = 4;
+
+// JsonQuotes
+const JS_QUOTES_KEEP: i32 = 2;
+const JS_QUOTES_OMIT: i32 = 3;
+
+/// Emit `JSON_VALUE`, `JSON_QUERY`, or `JSON_EXISTS`, dispatching on
+/// `JsonFuncExpr.op` - pgt_query parses all three SQL/JSON path functions
+/// into this one node, the same way [`super::json_table::emit_json_table`]
+/// handles `JSON_TABLE`.
+pub(super) fn emit_json_func_expr(e: &mut EventEmitter, n: &JsonFuncExpr) {
+    match n.op {
+        JSON_EXISTS_OP => emit_json_exists(e, n),
+        JSON_QUERY_OP => emit_json_query(e, n),
+        _ => emit_json_value(e, n),
+    }
+}
+
+fn emit_json_value(e: &mut EventEmitter, n: &JsonFuncExpr) {
+    e.group_start(GroupKind::JsonFuncExpr);
+    e.token(TokenKind::KEYWORD("JSON_VALUE".into()));
+    e.token(TokenKind::L_PAREN);
+
+    emit_context_and_path(e, n);
+    emit_passing(e, n);
+    emit_returning(e, n);
+    emit_behavior(e, n.on_empty.as_deref(), "EMPTY");
+    emit_behavior(e, n.on_error.as_deref(), "ERROR");
+
+    e.token(TokenKind::R_PAREN);
+    e.group_end();
+}
+
+fn emit_json_query(e: &mut EventEmitter, n: &JsonFuncExpr) {
+    e.group_start(GroupKind::JsonFuncExpr);
+    e.token(TokenKind::KEYWORD("JSON_QUERY".into()));
+    e.token(TokenKind::L_PAREN);
+
+    emit_context_and_path(e, n);
+    emit_passing(e, n);
+    emit_returning(e, n);
+
+    match n.wrapper {
+        JSW_UNCONDITIONAL => {
+            e.space();
+            e.token(TokenKind::WITH_KW);
+            e.space();
+            e.token(TokenKind::KEYWORD("UNCONDITIONAL".into()));
+            e.space();
+            e.token(TokenKind::KEYWORD("ARRAY".into()));
+            e.space();
+            e.token(TokenKind::KEYWORD("WRAPPER".into()));
+        }
+        JSW_CONDITIONAL => {
+            e.space();
+            e.token(TokenKind::WITH_KW);
+            e.space();
+            e.token(TokenKind::KEYWORD("CONDITIONAL".into()));
+            e.space();
+            e.token(TokenKind::KEYWORD("ARRAY".into()));
+            e.space();
+            e.token(TokenKind::KEYWORD("WRAPPER".into()));
+        }
+        JSW_NONE => {
+            e.space();
+            e.token(TokenKind::WITHOUT_KW);
+            e.space();
+            e.token(TokenKind::KEYWORD("ARRAY".into()));
+            e.space();
+            e.token(TokenKind::KEYWORD("WRAPPER".into()));
+        }
+        _ => {}
+    }
+
+    match n.quotes {
+        JS_QUOTES_KEEP => {
+            e.space();
+            e.token(TokenKind::KEYWORD("KEEP".into()));
+            e.space();
+            e.token(TokenKind::KEYWORD("QUOTES".into()));
+        }
+        JS_QUOTES_OMIT => {
+            e.space();
+            e.token(TokenKind::KEYWORD("OMIT".into()));
+            e.space();
+            e.token(TokenKind::KEYWORD("QUOTES".into()));
+        }
+        _ => {}
+    }
+
+    emit_behavior(e, n.on_empty.as_deref(), "EMPTY");
+    emit_behavior(e, n.on_error.as_deref(), "ERROR");
+
+    e.token(TokenKind::R_PAREN);
+    e.group_end();
+}
+
+fn emit_json_exists(e: &mut EventEmitter, n: &JsonFuncExpr) {
+    e.group_start(GroupKind::JsonFuncExpr);
+    e.token(TokenKind::KEYWORD("JSON_EXISTS".into()));
+    e.token(TokenKind::L_PAREN);
+
+    emit_context_and_path(e, n);
+    emit_passing(e, n);
+    emit_returning(e, n);
+    emit_behavior(e, n.on_error.as_deref(), "ERROR");
+
+    e.token(TokenKind::R_PAREN);
+    e.group_end();
+}
+
+/// Emit `context_item, pathspec` - the two arguments every SQL/JSON path
+/// function shares, e.g. the `foo, '$.bar'` in `JSON_VALUE(foo, '$.bar')`.
+fn emit_context_and_path(e: &mut EventEmitter, n: &JsonFuncExpr) {
+    if let Some(ref context) = n.context_item {
+        if let Some(ref raw_expr) = context.raw_expr {
+            super::emit_node(raw_expr, e);
+        }
+    }
+
+    e.token(TokenKind::COMMA);
+    e.space();
+
+    if let Some(ref pathspec) = n.pathspec {
+        if let Some(ref string_node) = pathspec.string {
+            super::emit_node(string_node, e);
+        }
+    }
+}
+
+/// Emit `PASSING val AS name, ...`, if present.
+fn emit_passing(e: &mut EventEmitter, n: &JsonFuncExpr) {
+    if n.passing.is_empty() {
+        return;
+    }
+
+    e.space();
+    e.token(TokenKind::KEYWORD("PASSING".into()));
+    e.space();
+    emit_comma_separated_list(e, &n.passing, super::emit_node);
+}
+
+/// Emit `RETURNING type`, if an explicit output type was given.
+fn emit_returning(e: &mut EventEmitter, n: &JsonFuncExpr) {
+    let Some(ref output) = n.output else {
+        return;
+    };
+    let Some(ref type_name) = output.type_name else {
+        return;
+    };
+
+    e.space();
+    e.token(TokenKind::RETURNING_KW);
+    e.space();
+    super::emit_type_name(e, type_name);
+}
+
+/// Emit a `<behavior> ON EMPTY`/`<behavior> ON ERROR` clause, if one is
+/// present. `on_kw` is `"EMPTY"` or `"ERROR"`.
+fn emit_behavior(e: &mut EventEmitter, behavior: Option<&JsonBehavior>, on_kw: &'static str) {
+    let Some(behavior) = behavior else {
+        return;
+    };
+
+    e.space();
+    match behavior.btype {
+        JSON_BEHAVIOR_ERROR => e.token(TokenKind::KEYWORD("ERROR".into())),
+        JSON_BEHAVIOR_NULL => e.token(TokenKind::KEYWORD("NULL".into())),
+        JSON_BEHAVIOR_TRUE => e.token(TokenKind::KEYWORD("TRUE".into())),
+        JSON_BEHAVIOR_FALSE => e.token(TokenKind::KEYWORD("FALSE".into())),
+        JSON_BEHAVIOR_UNKNOWN => e.token(TokenKind::KEYWORD("UNKNOWN".into())),
+        JSON_BEHAVIOR_EMPTY => e.token(TokenKind::KEYWORD("EMPTY".into())),
+        JSON_BEHAVIOR_EMPTY_ARRAY => {
+            e.token(TokenKind::KEYWORD("EMPTY".into()));
+            e.space();
+            e.token(TokenKind::KEYWORD("ARRAY".into()));
+        }
+        JSON_BEHAVIOR_EMPTY_OBJECT => {
+            e.token(TokenKind::KEYWORD("EMPTY".into()));
+            e.space();
+            e.token(TokenKind::KEYWORD("OBJECT".into()));
+        }
+        JSON_BEHAVIOR_DEFAULT => {
+            e.token(TokenKind::DEFAULT_KW);
+            if let Some(ref expr) = behavior.expr {
+                e.space();
+                super::emit_node(expr, e);
+            }
+        }
+        _ => {}
+    }
+    e.space();
+    e.token(TokenKind::ON_KW);
+    e.space();
+    // `on_kw` is always one of the `"EMPTY"`/`"ERROR"` literals the callers pass, so this
+    // borrows rather than allocating.
+    e.token(TokenKind::KEYWORD(Cow::Borrowed(on_kw)));
+}
@@ -17,23 +17,23 @@ pub(super) fn emit_json_is_predicate(e: &mut EventEmitter, n: &JsonIsPredicate)
 
     // item_type: JsTypeAny = 0, JsTypeObject = 1, JsTypeArray = 2, JsTypeScalar = 3
     match n.item_type {
-        0 => e.token(TokenKind::IDENT("JSON".to_string())),
+        0 => e.token(TokenKind::KEYWORD("JSON".into())),
         1 => {
-            e.token(TokenKind::IDENT("JSON".to_string()));
+            e.token(TokenKind::KEYWORD("JSON".into()));
             e.space();
-            e.token(TokenKind::IDENT("OBJECT".to_string()));
+            e.token(TokenKind::KEYWORD("OBJECT".into()));
         }
         2 => {
-            e.token(TokenKind::IDENT("JSON".to_string()));
+            e.token(TokenKind::KEYWORD("JSON".into()));
             e.space();
-            e.token(TokenKind::IDENT("ARRAY".to_string()));
+            e.token(TokenKind::KEYWORD("ARRAY".into()));
         }
         3 => {
-            e.token(TokenKind::IDENT("JSON".to_string()));
+            e.token(TokenKind::KEYWORD("JSON".into()));
             e.space();
-            e.token(TokenKind::IDENT("SCALAR".to_string()));
+            e.token(TokenKind::KEYWORD("SCALAR".into()));
         }
-        _ => e.token(TokenKind::IDENT("JSON".to_string())),
+        _ => e.token(TokenKind::KEYWORD("JSON".into())),
     }
 
     e.group_end();
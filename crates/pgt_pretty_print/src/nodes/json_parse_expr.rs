@@ -7,7 +7,7 @@ use pgt_query::protobuf::JsonParseExpr;
 pub(super) fn emit_json_parse_expr(e: &mut EventEmitter, n: &JsonParseExpr) {
     e.group_start(GroupKind::JsonParseExpr);
 
-    e.token(TokenKind::IDENT("JSON".to_string()));
+    e.token(TokenKind::KEYWORD("JSON".into()));
     e.token(TokenKind::L_PAREN);
 
     if let Some(ref expr) = n.expr {
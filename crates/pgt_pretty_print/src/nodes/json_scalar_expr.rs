@@ -7,7 +7,7 @@ use pgt_query::protobuf::JsonScalarExpr;
 pub(super) fn emit_json_scalar_expr(e: &mut EventEmitter, n: &JsonScalarExpr) {
     e.group_start(GroupKind::JsonScalarExpr);
 
-    e.token(TokenKind::IDENT("JSON_SCALAR".to_string()));
+    e.token(TokenKind::KEYWORD("JSON_SCALAR".into()));
     e.token(TokenKind::L_PAREN);
 
     if let Some(ref expr) = n.expr {
@@ -1,14 +1,45 @@
+use std::borrow::Cow;
+
 use crate::{
     TokenKind,
     emitter::{EventEmitter, GroupKind},
     nodes::node_list::emit_comma_separated_list,
 };
-use pgt_query::{NodeEnum, protobuf::JsonTable};
+use pgt_query::{
+    NodeEnum,
+    protobuf::{JsonBehavior, JsonTable, JsonTableColumn},
+};
+
+// JsonTableColumnType, as pgt_query's protobuf numbers it: PostgreSQL's enum
+// order in parsenodes.h, shifted up by one for the leading `Undefined = 0`.
+const JTC_FOR_ORDINALITY: i32 = 1;
+const JTC_EXISTS: i32 = 3;
+const JTC_FORMATTED: i32 = 4;
+const JTC_NESTED: i32 = 5;
+
+// JsonFormatType
+const JS_FORMAT_JSON: i32 = 2;
+
+// JsonWrapper
+const JSW_NONE: i32 = 2;
+const JSW_CONDITIONAL: i32 = 3;
+const JSW_UNCONDITIONAL: i32 = 4;
+
+// JsonBehaviorType
+const JSON_BEHAVIOR_NULL: i32 = 1;
+const JSON_BEHAVIOR_ERROR: i32 = 2;
+const JSON_BEHAVIOR_EMPTY: i32 = 3;
+const JSON_BEHAVIOR_TRUE: i32 = 4;
+const JSON_BEHAVIOR_FALSE: i32 = 5;
+const JSON_BEHAVIOR_UNKNOWN: i32 = 6;
+const JSON_BEHAVIOR_EMPTY_ARRAY: i32 = 7;
+const JSON_BEHAVIOR_EMPTY_OBJECT: i32 = 8;
+const JSON_BEHAVIOR_DEFAULT: i32 = 9;
 
 pub(super) fn emit_json_table(e: &mut EventEmitter, n: &JsonTable) {
     e.group_start(GroupKind::JsonTable);
 
-    e.token(TokenKind::IDENT("JSON_TABLE".to_string()));
+    e.token(TokenKind::KEYWORD("JSON_TABLE".into()));
     e.token(TokenKind::L_PAREN);
 
     // Context item (the JSON data)
@@ -31,43 +62,25 @@ pub(super) fn emit_json_table(e: &mut EventEmitter, n: &JsonTable) {
     // PASSING clause
     if !n.passing.is_empty() {
         e.space();
-        e.token(TokenKind::IDENT("PASSING".to_string()));
+        e.token(TokenKind::KEYWORD("PASSING".into()));
         e.space();
         emit_comma_separated_list(e, &n.passing, super::emit_node);
     }
 
     // COLUMNS clause
     e.space();
-    e.token(TokenKind::IDENT("COLUMNS".to_string()));
+    e.token(TokenKind::KEYWORD("COLUMNS".into()));
     e.space();
     e.token(TokenKind::L_PAREN);
 
     if !n.columns.is_empty() {
+        e.indent_start();
         emit_comma_separated_list(e, &n.columns, |node, e| {
             if let Some(NodeEnum::JsonTableColumn(col)) = &node.node {
-                // Column name
-                e.token(TokenKind::IDENT(col.name.clone()));
-
-                // Column type (regular, ordinality, exists, query, etc.)
-                // For now, emit type name for regular columns
-                if let Some(ref type_name) = col.type_name {
-                    e.space();
-                    super::emit_type_name(e, type_name);
-                }
-
-                // Path specification for the column
-                if let Some(ref pathspec) = col.pathspec {
-                    e.space();
-                    e.token(TokenKind::IDENT("PATH".to_string()));
-                    e.space();
-                    if let Some(ref string_node) = pathspec.string {
-                        super::emit_node(string_node, e);
-                    }
-                }
-
-                // TODO: Handle ON EMPTY, ON ERROR, nested columns
+                emit_json_table_column(e, col);
             }
         });
+        e.indent_end();
     }
 
     e.token(TokenKind::R_PAREN);
@@ -81,3 +94,155 @@ pub(super) fn emit_json_table(e: &mut EventEmitter, n: &JsonTable) {
 
     e.group_end();
 }
+
+/// Emit one `JsonTableColumn`, recursing through `NESTED PATH ... COLUMNS
+/// (...)` sub-lists via this same function.
+fn emit_json_table_column(e: &mut EventEmitter, col: &JsonTableColumn) {
+    if col.coltype == JTC_NESTED {
+        e.token(TokenKind::KEYWORD("NESTED".into()));
+        e.space();
+        emit_path(e, col);
+        e.space();
+        e.token(TokenKind::KEYWORD("COLUMNS".into()));
+        e.space();
+        e.token(TokenKind::L_PAREN);
+        emit_comma_separated_list(e, &col.columns, |node, e| {
+            if let Some(NodeEnum::JsonTableColumn(nested)) = &node.node {
+                emit_json_table_column(e, nested);
+            }
+        });
+        e.token(TokenKind::R_PAREN);
+        return;
+    }
+
+    e.token(TokenKind::IDENT(col.name.clone().into()));
+
+    if col.coltype == JTC_FOR_ORDINALITY {
+        e.space();
+        e.token(TokenKind::FOR_KW);
+        e.space();
+        e.token(TokenKind::KEYWORD("ORDINALITY".into()));
+        return;
+    }
+
+    if let Some(ref type_name) = col.type_name {
+        e.space();
+        super::emit_type_name(e, type_name);
+    }
+
+    if col.coltype == JTC_EXISTS {
+        e.space();
+        e.token(TokenKind::EXISTS_KW);
+        e.space();
+        emit_path(e, col);
+        emit_behavior(e, col.on_error.as_deref(), "ERROR");
+        return;
+    }
+
+    // JTC_FORMATTED (a "query" column) carries an explicit FORMAT JSON;
+    // JTC_REGULAR (a scalar column) doesn't.
+    if col.coltype == JTC_FORMATTED {
+        e.space();
+        e.token(TokenKind::KEYWORD("FORMAT".into()));
+        e.space();
+        e.token(TokenKind::KEYWORD("JSON".into()));
+    }
+
+    e.space();
+    emit_path(e, col);
+
+    if let Some(ref format) = col.format {
+        if format.format_type == JS_FORMAT_JSON && col.coltype != JTC_FORMATTED {
+            e.space();
+            e.token(TokenKind::KEYWORD("FORMAT".into()));
+            e.space();
+            e.token(TokenKind::KEYWORD("JSON".into()));
+        }
+    }
+
+    match col.wrapper {
+        JSW_NONE => {
+            e.space();
+            e.token(TokenKind::WITHOUT_KW);
+            e.space();
+            e.token(TokenKind::KEYWORD("ARRAY".into()));
+            e.space();
+            e.token(TokenKind::KEYWORD("WRAPPER".into()));
+        }
+        JSW_UNCONDITIONAL => {
+            e.space();
+            e.token(TokenKind::WITH_KW);
+            e.space();
+            e.token(TokenKind::KEYWORD("ARRAY".into()));
+            e.space();
+            e.token(TokenKind::KEYWORD("WRAPPER".into()));
+        }
+        JSW_CONDITIONAL => {
+            e.space();
+            e.token(TokenKind::WITH_KW);
+            e.space();
+            e.token(TokenKind::KEYWORD("CONDITIONAL".into()));
+            e.space();
+            e.token(TokenKind::KEYWORD("ARRAY".into()));
+            e.space();
+            e.token(TokenKind::KEYWORD("WRAPPER".into()));
+        }
+        _ => {}
+    }
+
+    emit_behavior(e, col.on_empty.as_deref(), "EMPTY");
+    emit_behavior(e, col.on_error.as_deref(), "ERROR");
+}
+
+/// Emit `PATH '<pathspec>'` for a column's path specification.
+fn emit_path(e: &mut EventEmitter, col: &JsonTableColumn) {
+    e.token(TokenKind::KEYWORD("PATH".into()));
+    e.space();
+    if let Some(ref pathspec) = col.pathspec {
+        if let Some(ref string_node) = pathspec.string {
+            super::emit_node(string_node, e);
+        }
+    }
+}
+
+/// Emit a `<behavior> ON EMPTY`/`<behavior> ON ERROR` clause, if one is
+/// present. `on_kw` is `"EMPTY"` or `"ERROR"`.
+fn emit_behavior(e: &mut EventEmitter, behavior: Option<&JsonBehavior>, on_kw: &'static str) {
+    let Some(behavior) = behavior else {
+        return;
+    };
+
+    e.space();
+    match behavior.btype {
+        JSON_BEHAVIOR_ERROR => e.token(TokenKind::KEYWORD("ERROR".into())),
+        JSON_BEHAVIOR_NULL => e.token(TokenKind::KEYWORD("NULL".into())),
+        JSON_BEHAVIOR_TRUE => e.token(TokenKind::KEYWORD("TRUE".into())),
+        JSON_BEHAVIOR_FALSE => e.token(TokenKind::KEYWORD("FALSE".into())),
+        JSON_BEHAVIOR_UNKNOWN => e.token(TokenKind::KEYWORD("UNKNOWN".into())),
+        JSON_BEHAVIOR_EMPTY => e.token(TokenKind::KEYWORD("EMPTY".into())),
+        JSON_BEHAVIOR_EMPTY_ARRAY => {
+            e.token(TokenKind::KEYWORD("EMPTY".into()));
+            e.space();
+            e.token(TokenKind::KEYWORD("ARRAY".into()));
+        }
+        JSON_BEHAVIOR_EMPTY_OBJECT => {
+            e.token(TokenKind::KEYWORD("EMPTY".into()));
+            e.space();
+            e.token(TokenKind::KEYWORD("OBJECT".into()));
+        }
+        JSON_BEHAVIOR_DEFAULT => {
+            e.token(TokenKind::DEFAULT_KW);
+            if let Some(ref expr) = behavior.expr {
+                e.space();
+                super::emit_node(expr, e);
+            }
+        }
+        _ => {}
+    }
+    e.space();
+    e.token(TokenKind::ON_KW);
+    e.space();
+    // `on_kw` is always one of the `"EMPTY"`/`"ERROR"` literals the callers pass, so this
+    // borrows rather than allocating.
+    e.token(TokenKind::KEYWORD(Cow::Borrowed(on_kw)));
+}
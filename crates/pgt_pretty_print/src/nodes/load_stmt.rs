@@ -12,7 +12,7 @@ pub(super) fn emit_load_stmt(e: &mut EventEmitter, n: &LoadStmt) {
 
     if !n.filename.is_empty() {
         e.space();
-        e.token(TokenKind::IDENT(format!("'{}'", n.filename)));
+        e.token(TokenKind::IDENT(format!("'{}'", n.filename).into()));
     }
 
     e.token(TokenKind::SEMICOLON);
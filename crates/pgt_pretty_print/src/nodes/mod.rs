@@ -0,0 +1,163 @@
+mod a_expr;
+mod a_star;
+mod alter_enum_stmt;
+mod alter_foreign_server_stmt;
+mod alter_object_depends_stmt;
+mod alter_object_schema_stmt;
+mod alter_owner_stmt;
+mod alter_subscription_stmt;
+mod bool_expr;
+mod boolean;
+mod create_cast_stmt;
+mod create_foreign_server_stmt;
+mod create_subscription_stmt;
+mod create_table_as_stmt;
+mod create_table_space_stmt;
+mod def_elem;
+mod define_stmt;
+mod delete_stmt;
+mod do_stmt;
+mod insert_stmt;
+mod join_expr;
+mod json_func_expr;
+mod json_is_predicate;
+mod json_parse_expr;
+mod json_scalar_expr;
+mod json_table;
+mod load_stmt;
+mod node_list;
+mod object_with_args;
+mod on_conflict_clause;
+mod precedence;
+mod range_var;
+mod rename_stmt;
+mod res_target;
+mod role_spec;
+mod row_expr;
+mod scalar_array_op_expr;
+mod sec_label_stmt;
+mod select_stmt;
+mod string;
+mod type_name;
+mod view_stmt;
+mod window_def;
+mod with_clause;
+
+use pgt_query::{Node, NodeEnum};
+
+use crate::emitter::EventEmitter;
+
+pub(crate) use def_elem::emit_options_def_elem;
+pub(crate) use delete_stmt::emit_delete_stmt_no_semicolon;
+pub(crate) use insert_stmt::emit_insert_stmt_no_semicolon;
+pub(crate) use node_list::{
+    emit_comma_separated_list, emit_dot_separated_list, emit_identifier_list,
+};
+pub(crate) use object_with_args::{emit_object_name_only, emit_object_with_args};
+pub(crate) use on_conflict_clause::emit_on_conflict_clause;
+pub(crate) use range_var::{emit_alias, emit_range_var};
+pub(crate) use role_spec::emit_role_spec;
+pub(crate) use select_stmt::emit_select_stmt_no_semicolon;
+pub(crate) use string::{emit_identifier, emit_identifier_maybe_quoted, emit_string_identifier};
+pub(crate) use type_name::emit_type_name;
+pub(crate) use window_def::emit_window_def;
+pub(crate) use with_clause::emit_with_clause;
+
+/// Emit a single AST node, unwrapping the `Option<NodeEnum>` that every
+/// `pgt_query` `Node` carries. Nodes that can't appear standalone (e.g.
+/// `WithClause`, `DefElem`, `RoleSpec`) don't go through here - their
+/// owning statement's emitter calls the dedicated `emit_*` function above
+/// directly once it has downcast the field.
+pub(crate) fn emit_node(node: &Node, e: &mut EventEmitter) {
+    if let Some(inner) = node.node.as_ref() {
+        emit_node_enum(inner, e);
+    }
+}
+
+/// The `location` byte offset of node kinds whose emitter calls
+/// [`EventEmitter::enter_node`]/[`EventEmitter::exit_node`] around their own
+/// emission, for [`crate::comments::CommentHook`] (or any other
+/// [`crate::emitter::EmitterHook`]) to key off. Only node kinds confirmed to
+/// carry a `location` field are listed; everything else yields `None`, which
+/// is a safe no-op for hooks rather than a wrong guess.
+fn node_location(node: &NodeEnum) -> Option<i32> {
+    match node {
+        NodeEnum::AExpr(n) => Some(n.location),
+        NodeEnum::ResTarget(n) => Some(n.location),
+        NodeEnum::TypeName(n) => Some(n.location),
+        _ => None,
+    }
+}
+
+/// Emit the AST root (or any node already unwrapped to a `NodeEnum`).
+pub fn emit_node_enum(node: &NodeEnum, e: &mut EventEmitter) {
+    let location = node_location(node);
+    if let Some(location) = location {
+        e.enter_node(location);
+    }
+    emit_node_enum_inner(node, e);
+    if let Some(location) = location {
+        e.exit_node(location);
+    }
+}
+
+fn emit_node_enum_inner(node: &NodeEnum, e: &mut EventEmitter) {
+    match node {
+        NodeEnum::AExpr(n) => a_expr::emit_a_expr(e, n),
+        NodeEnum::AStar(n) => a_star::emit_a_star(e, n),
+        NodeEnum::AlterEnumStmt(n) => alter_enum_stmt::emit_alter_enum_stmt(e, n),
+        NodeEnum::AlterForeignServerStmt(n) => {
+            alter_foreign_server_stmt::emit_alter_foreign_server_stmt(e, n)
+        }
+        NodeEnum::AlterObjectDependsStmt(n) => {
+            alter_object_depends_stmt::emit_alter_object_depends_stmt(e, n)
+        }
+        NodeEnum::AlterObjectSchemaStmt(n) => {
+            alter_object_schema_stmt::emit_alter_object_schema_stmt(e, n)
+        }
+        NodeEnum::AlterOwnerStmt(n) => alter_owner_stmt::emit_alter_owner_stmt(e, n),
+        NodeEnum::AlterSubscriptionStmt(n) => {
+            alter_subscription_stmt::emit_alter_subscription_stmt(e, n)
+        }
+        NodeEnum::BoolExpr(n) => bool_expr::emit_bool_expr(e, n),
+        NodeEnum::Boolean(n) => boolean::emit_boolean(e, n),
+        NodeEnum::CreateCastStmt(n) => create_cast_stmt::emit_create_cast_stmt(e, n),
+        NodeEnum::CreateForeignServerStmt(n) => {
+            create_foreign_server_stmt::emit_create_foreign_server_stmt(e, n)
+        }
+        NodeEnum::CreateSubscriptionStmt(n) => {
+            create_subscription_stmt::emit_create_subscription_stmt(e, n)
+        }
+        NodeEnum::CreateTableAsStmt(n) => create_table_as_stmt::emit_create_table_as_stmt(e, n),
+        NodeEnum::CreateTableSpaceStmt(n) => {
+            create_table_space_stmt::emit_create_table_space_stmt(e, n)
+        }
+        NodeEnum::DefineStmt(n) => define_stmt::emit_define_stmt(e, n),
+        NodeEnum::DeleteStmt(n) => delete_stmt::emit_delete_stmt(e, n),
+        NodeEnum::DoStmt(n) => do_stmt::emit_do_stmt(e, n),
+        NodeEnum::InsertStmt(n) => insert_stmt::emit_insert_stmt(e, n),
+        NodeEnum::JoinExpr(n) => join_expr::emit_join_expr(e, n),
+        NodeEnum::JsonFuncExpr(n) => json_func_expr::emit_json_func_expr(e, n),
+        NodeEnum::JsonIsPredicate(n) => json_is_predicate::emit_json_is_predicate(e, n),
+        NodeEnum::JsonParseExpr(n) => json_parse_expr::emit_json_parse_expr(e, n),
+        NodeEnum::JsonScalarExpr(n) => json_scalar_expr::emit_json_scalar_expr(e, n),
+        NodeEnum::JsonTable(n) => json_table::emit_json_table(e, n),
+        NodeEnum::LoadStmt(n) => load_stmt::emit_load_stmt(e, n),
+        NodeEnum::ObjectWithArgs(n) => object_with_args::emit_object_with_args(e, n),
+        NodeEnum::RenameStmt(n) => rename_stmt::emit_rename_stmt(e, n),
+        NodeEnum::ResTarget(n) => res_target::emit_res_target(e, n),
+        NodeEnum::RowExpr(n) => row_expr::emit_row_expr(e, n),
+        NodeEnum::ScalarArrayOpExpr(n) => {
+            scalar_array_op_expr::emit_scalar_array_op_expr(e, n)
+        }
+        NodeEnum::SecLabelStmt(n) => sec_label_stmt::emit_sec_label_stmt(e, n),
+        NodeEnum::SelectStmt(n) => select_stmt::emit_select_stmt(e, n),
+        NodeEnum::String(n) => string::emit_string(e, n),
+        NodeEnum::TypeName(n) => type_name::emit_type_name(e, n),
+        NodeEnum::ViewStmt(n) => view_stmt::emit_view_stmt(e, n),
+        // Every other node kind isn't supported by the formatter yet; we
+        // drop it rather than panic so a document with an unhandled
+        // construct still formats everything around it.
+        _ => {}
+    }
+}
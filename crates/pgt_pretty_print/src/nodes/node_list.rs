@@ -0,0 +1,104 @@
+use pgt_query::protobuf::Node;
+
+use crate::TokenKind;
+use crate::emitter::{EventEmitter, GroupKind, LineType, SeparatorTactic};
+
+/// Emit `items`, separated according to [`EventEmitter::config`]'s
+/// [`SeparatorTactic`], using `emit_item` for each element.
+///
+/// `SeparatorTactic::Horizontal` keeps the list on one line regardless of
+/// width. `Vertical` (the default) wraps the whole list in its own
+/// [`crate::emitter::BreakStyle::Inconsistent`] group, so a long list (an
+/// `IN (...)` list, a function's argument list, a `RETURNING` projection,
+/// ...) wraps only the items that would actually overflow the margin,
+/// independently of whatever group encloses it - today's long-standing
+/// behavior. `VerticalTrailingComma` wraps in a
+/// [`crate::emitter::BreakStyle::Consistent`] group instead - every item
+/// wraps once any of them would overflow - so the trailing comma it adds
+/// (an [`EventEmitter::conditional_token`], which only renders once the
+/// group actually breaks) reliably tracks whether the list as a whole
+/// broke, never appearing on a list that stays flat.
+pub(super) fn emit_comma_separated_list<T>(
+    e: &mut EventEmitter,
+    items: &[T],
+    mut emit_item: impl FnMut(&T, &mut EventEmitter),
+) {
+    let tactic = e.config().separator_tactic;
+
+    if tactic == SeparatorTactic::Horizontal {
+        for (i, item) in items.iter().enumerate() {
+            if i > 0 {
+                e.token(TokenKind::COMMA);
+                e.space();
+            }
+            emit_item(item, e);
+        }
+        return;
+    }
+
+    if tactic == SeparatorTactic::VerticalTrailingComma {
+        e.group_start(GroupKind::CommaList);
+    } else {
+        e.group_start_inconsistent(GroupKind::CommaList);
+    }
+    for (i, item) in items.iter().enumerate() {
+        if i > 0 {
+            e.token(TokenKind::COMMA);
+            e.line(LineType::SoftOrSpace);
+        }
+        emit_item(item, e);
+    }
+    if tactic == SeparatorTactic::VerticalTrailingComma && !items.is_empty() {
+        // Only render if the list actually breaks - `(a, b, c)` must stay
+        // comma-free before the closing paren when it fits on one line.
+        e.conditional_token(TokenKind::COMMA);
+        e.line(LineType::Soft);
+    }
+    e.group_end();
+}
+
+/// Emit `items`, separated by `<space><keyword><space>`, using `emit_item`
+/// for each element.
+pub(super) fn emit_keyword_separated_list<T>(
+    e: &mut EventEmitter,
+    items: &[T],
+    keyword: TokenKind,
+    mut emit_item: impl FnMut(&T, &mut EventEmitter),
+) {
+    for (i, item) in items.iter().enumerate() {
+        if i > 0 {
+            e.space();
+            e.token(keyword.clone());
+            e.space();
+        }
+        emit_item(item, e);
+    }
+}
+
+/// Emit a list of `String` nodes as comma-separated, properly-quoted
+/// identifiers, e.g. the publication list in `CREATE`/`ALTER SUBSCRIPTION
+/// ... PUBLICATION name1, name2`.
+pub(super) fn emit_identifier_list(e: &mut EventEmitter, items: &[Node]) {
+    emit_comma_separated_list(e, items, |item, e| {
+        if let Some(pgt_query::NodeEnum::String(s)) = &item.node {
+            super::string::emit_identifier_maybe_quoted(e, &s.sval);
+        } else {
+            super::emit_node(item, e);
+        }
+    });
+}
+
+/// Emit a qualified name (a list of `String` nodes) separated by `.`, e.g.
+/// `schema.name`.
+pub(super) fn emit_dot_separated_list(e: &mut EventEmitter, items: &[Node]) {
+    for (i, item) in items.iter().enumerate() {
+        if i > 0 {
+            e.token(TokenKind::DOT);
+        }
+        if let Some(pgt_query::NodeEnum::String(s)) = &item.node {
+            super::string::emit_identifier_maybe_quoted(e, &s.sval);
+        } else {
+            super::emit_node(item, e);
+        }
+    }
+}
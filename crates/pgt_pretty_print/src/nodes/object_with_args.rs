@@ -16,6 +16,9 @@ pub(super) fn emit_object_name_only(e: &mut EventEmitter, n: &ObjectWithArgs) {
 }
 
 fn emit_object_with_args_impl(e: &mut EventEmitter, n: &ObjectWithArgs, with_parens: bool) {
+    // Unlike `ResTarget`/`AExpr`, `ObjectWithArgs` carries no `location` of
+    // its own (nor do the bare `String` nodes in `objname`) - there's no
+    // source byte offset here to attach a span to.
     e.group_start(GroupKind::ObjectWithArgs);
 
     // Object name (qualified name)
@@ -10,7 +10,7 @@ pub(super) fn emit_on_conflict_clause(e: &mut EventEmitter, n: &OnConflictClause
     e.space();
     e.token(TokenKind::ON_KW);
     e.space();
-    e.token(TokenKind::IDENT("CONFLICT".to_string()));
+    e.token(TokenKind::KEYWORD("CONFLICT".into()));
 
     // Emit the inference clause (target columns or constraint name)
     if let Some(ref infer) = n.infer {
@@ -25,7 +25,7 @@ pub(super) fn emit_on_conflict_clause(e: &mut EventEmitter, n: &OnConflictClause
     match n.action {
         2 => {
             // OnconflictNothing
-            e.token(TokenKind::IDENT("NOTHING".to_string()));
+            e.token(TokenKind::KEYWORD("NOTHING".into()));
         }
         3 => {
             // OnconflictUpdate
@@ -65,9 +65,9 @@ fn emit_infer_clause(e: &mut EventEmitter, n: &InferClause) {
         e.space();
         e.token(TokenKind::ON_KW);
         e.space();
-        e.token(TokenKind::IDENT("CONSTRAINT".to_string()));
+        e.token(TokenKind::KEYWORD("CONSTRAINT".into()));
         e.space();
-        e.token(TokenKind::IDENT(n.conname.clone()));
+        e.token(TokenKind::IDENT(n.conname.clone().into()));
     } else if !n.index_elems.is_empty() {
         // Emit index elements (columns)
         e.space();
@@ -0,0 +1,156 @@
+//! Precedence-aware parenthesization for expression emission.
+//!
+//! `pgt_query`'s AST has already thrown away the parentheses the user
+//! wrote - it only records the *parsed* tree, where e.g. `a OR (b AND c)`
+//! and `a OR b AND c` are indistinguishable at the `BoolExpr` level (`AND`
+//! already binds tighter, so both parse the same way). Re-emitting the
+//! tree flatly is fine for that case, but `(a OR b) AND c` would come back
+//! out as `a OR b AND c` - silently changing what it means.
+//!
+//! This module assigns each expression node a precedence/associativity and
+//! has callers route child operands through [`emit_operand`], which wraps
+//! a child in parentheses whenever it binds less tightly than its parent
+//! (or equally tightly on a side that would otherwise be ambiguous).
+
+use pgt_query::protobuf::{AExprKind, BoolExprType};
+use pgt_query::{Node, NodeEnum};
+
+use crate::TokenKind;
+use crate::emitter::EventEmitter;
+
+/// Operator precedence, lowest to highest, mirroring the `%left`/`%right`/
+/// `%nonassoc` declarations in Postgres' own grammar
+/// (`src/backend/parser/gram.y`).
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord)]
+pub(super) enum Precedence {
+    Or,
+    And,
+    Not,
+    /// `IS`, `ISNULL`, the comparison operators (`<`, `>`, `=`, `<=`, `>=`,
+    /// `<>`) - and, conservatively, any other (e.g. user-defined) binary
+    /// operator not covered by a more specific tier below.
+    Comparison,
+    /// `BETWEEN`, `IN`, `LIKE`, `ILIKE`, `SIMILAR TO`.
+    PatternMatch,
+    /// `+`, `-`.
+    Additive,
+    /// `*`, `/`, `%`.
+    Multiplicative,
+    /// `^`, left-associative despite the mathematical notation suggesting
+    /// otherwise - Postgres' grammar declares it `%left '^'`.
+    Exponent,
+    /// Unary `+`/`-`. Binds tighter than `^`, so `-2 ^ 2` is `(-2) ^ 2`, not
+    /// `-(2 ^ 2)`.
+    UnaryMinus,
+    /// `::`.
+    Cast,
+    /// `[]`, `.`.
+    Accessor,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub(super) enum Assoc {
+    Left,
+    Right,
+    NonAssoc,
+}
+
+/// Which side of its parent a child expression occupies. Only matters when
+/// child and parent share the same precedence.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub(super) enum Side {
+    Left,
+    Right,
+}
+
+/// The precedence/associativity a node is emitted at, or `None` if it's
+/// atomic (a literal, column reference, function call, ...) and never
+/// needs parenthesizing around it.
+fn precedence_of(node: &NodeEnum) -> Option<(Precedence, Assoc)> {
+    match node {
+        NodeEnum::BoolExpr(b) => match b.boolop() {
+            BoolExprType::OrExpr => Some((Precedence::Or, Assoc::Left)),
+            BoolExprType::AndExpr => Some((Precedence::And, Assoc::Left)),
+            BoolExprType::NotExpr => Some((Precedence::Not, Assoc::NonAssoc)),
+            BoolExprType::Undefined => None,
+        },
+        NodeEnum::AExpr(a) => match a.kind() {
+            AExprKind::AexprOp => {
+                if a.lexpr.is_none() {
+                    Some((Precedence::UnaryMinus, Assoc::NonAssoc))
+                } else {
+                    Some(operator_precedence(&a.name))
+                }
+            }
+            AExprKind::AexprOpAny | AExprKind::AexprOpAll => Some(operator_precedence(&a.name)),
+            AExprKind::AexprDistinct | AExprKind::AexprNotDistinct => {
+                Some((Precedence::Comparison, Assoc::NonAssoc))
+            }
+            AExprKind::AexprIn
+            | AExprKind::AexprLike
+            | AExprKind::AexprIlike
+            | AExprKind::AexprSimilar
+            | AExprKind::AexprBetween
+            | AExprKind::AexprNotBetween
+            | AExprKind::AexprBetweenSym
+            | AExprKind::AexprNotBetweenSym => Some((Precedence::PatternMatch, Assoc::NonAssoc)),
+            // NULLIF(a, b) is function-call syntax; its arguments are never
+            // ambiguous and don't need wrapping.
+            AExprKind::AexprNullif | AExprKind::Undefined => None,
+        },
+        NodeEnum::TypeCast(_) => Some((Precedence::Cast, Assoc::Left)),
+        _ => None,
+    }
+}
+
+/// Map an `AExpr`'s operator name to the precedence/associativity it binds
+/// at. Falls back to `Comparison` - Postgres' default binding for any
+/// operator not covered by a dedicated tier - for names this doesn't list.
+pub(super) fn operator_precedence(name: &[Node]) -> (Precedence, Assoc) {
+    let op = name.iter().find_map(|n| match n.node.as_ref() {
+        Some(NodeEnum::String(s)) => Some(s.sval.as_str()),
+        _ => None,
+    });
+
+    match op {
+        Some("<") | Some(">") | Some("=") | Some("<=") | Some(">=") | Some("<>") | Some("!=") => {
+            (Precedence::Comparison, Assoc::NonAssoc)
+        }
+        Some("+") | Some("-") => (Precedence::Additive, Assoc::Left),
+        Some("*") | Some("/") | Some("%") => (Precedence::Multiplicative, Assoc::Left),
+        Some("^") => (Precedence::Exponent, Assoc::Left),
+        _ => (Precedence::Comparison, Assoc::NonAssoc),
+    }
+}
+
+/// Would a child emitted at `child` (on `side` of a parent emitted at
+/// `parent`) need wrapping in parentheses to preserve its grouping?
+fn needs_parens((child, assoc): (Precedence, Assoc), parent: Precedence, side: Side) -> bool {
+    child < parent
+        || (child == parent
+            && match (assoc, side) {
+                (Assoc::Left, Side::Right) => true,
+                (Assoc::Right, Side::Left) => true,
+                (Assoc::NonAssoc, _) => true,
+                _ => false,
+            })
+}
+
+/// Emit `node` as the `side` operand of a parent emitted at `parent`
+/// precedence, wrapping it in `(...)` if that's needed to preserve its
+/// original grouping.
+pub(super) fn emit_operand(e: &mut EventEmitter, node: &Node, parent: Precedence, side: Side) {
+    let wrap = node
+        .node
+        .as_ref()
+        .and_then(precedence_of)
+        .is_some_and(|p| needs_parens(p, parent, side));
+
+    if wrap {
+        e.token(TokenKind::L_PAREN);
+        super::emit_node(node, e);
+        e.token(TokenKind::R_PAREN);
+    } else {
+        super::emit_node(node, e);
+    }
+}
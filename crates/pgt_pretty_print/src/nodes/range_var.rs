@@ -0,0 +1,52 @@
+use pgt_query::protobuf::{Alias, RangeVar};
+
+use crate::TokenKind;
+use crate::emitter::EventEmitter;
+
+use super::node_list::emit_comma_separated_list;
+use super::string::{emit_identifier_maybe_quoted, emit_identifier_maybe_quoted_at};
+
+/// Emit a table reference: `[catalog.][schema.]name[ AS alias]`.
+pub(super) fn emit_range_var(e: &mut EventEmitter, n: &RangeVar) {
+    if !n.catalogname.is_empty() {
+        emit_identifier_maybe_quoted(e, &n.catalogname);
+        e.token(TokenKind::DOT);
+    }
+    if !n.schemaname.is_empty() {
+        emit_identifier_maybe_quoted(e, &n.schemaname);
+        e.token(TokenKind::DOT);
+    }
+
+    // `location` is the start of the whole (possibly catalog/schema
+    // qualified) reference; it only lines up with `relname` itself when
+    // there's no catalog/schema prefix ahead of it.
+    if n.catalogname.is_empty() && n.schemaname.is_empty() {
+        emit_identifier_maybe_quoted_at(e, &n.relname, n.location);
+    } else {
+        emit_identifier_maybe_quoted(e, &n.relname);
+    }
+
+    if let Some(ref alias) = n.alias {
+        e.space();
+        emit_alias(e, alias);
+    }
+}
+
+/// Emit `AS name[(col1, col2, ...)]`.
+pub(super) fn emit_alias(e: &mut EventEmitter, n: &Alias) {
+    e.token(TokenKind::AS_KW);
+    e.space();
+    emit_identifier_maybe_quoted(e, &n.aliasname);
+
+    if !n.colnames.is_empty() {
+        e.token(TokenKind::L_PAREN);
+        emit_comma_separated_list(e, &n.colnames, |node, e| {
+            if let Some(pgt_query::NodeEnum::String(s)) = &node.node {
+                emit_identifier_maybe_quoted(e, &s.sval);
+            } else {
+                super::emit_node(node, e);
+            }
+        });
+        e.token(TokenKind::R_PAREN);
+    }
+}
@@ -5,67 +5,308 @@ use crate::{
     emitter::{EventEmitter, GroupKind},
 };
 
+/// `DropBehavior`'s wire values, as pgt_query's protobuf numbers them
+/// (`src/include/nodes/parsenodes.h`'s order, shifted up by one for the
+/// leading `Undefined = 0`) - the only `RenameStmt` form that carries one is
+/// `ALTER TYPE ... RENAME ATTRIBUTE ... [CASCADE|RESTRICT]`.
+const DROP_RESTRICT: i32 = 1;
+const DROP_CASCADE: i32 = 2;
+
 pub(super) fn emit_rename_stmt(e: &mut EventEmitter, n: &RenameStmt) {
     e.group_start(GroupKind::RenameStmt);
 
     e.token(TokenKind::ALTER_KW);
     e.space();
 
-    // ObjectType - map rename_type to SQL keyword using ObjectType enum
-    match n.rename_type {
-        x if x == ObjectType::ObjectTable as i32 => e.token(TokenKind::TABLE_KW),
-        x if x == ObjectType::ObjectSequence as i32 => e.token(TokenKind::SEQUENCE_KW),
-        x if x == ObjectType::ObjectView as i32 => e.token(TokenKind::VIEW_KW),
-        x if x == ObjectType::ObjectIndex as i32 => e.token(TokenKind::INDEX_KW),
-        x if x == ObjectType::ObjectType as i32 => e.token(TokenKind::TYPE_KW),
-        x if x == ObjectType::ObjectDomain as i32 => e.token(TokenKind::DOMAIN_KW),
-        x if x == ObjectType::ObjectDatabase as i32 => e.token(TokenKind::DATABASE_KW),
-        x if x == ObjectType::ObjectSchema as i32 => e.token(TokenKind::SCHEMA_KW),
-        x if x == ObjectType::ObjectFunction as i32 => e.token(TokenKind::FUNCTION_KW),
-        x if x == ObjectType::ObjectProcedure as i32 => e.token(TokenKind::PROCEDURE_KW),
-        x if x == ObjectType::ObjectColumn as i32 => e.token(TokenKind::COLUMN_KW),
-        x if x == ObjectType::ObjectMatview as i32 => {
+    match ObjectType::try_from(n.rename_type).unwrap_or(ObjectType::Undefined) {
+        ObjectType::ObjectTable => emit_relation_rename(e, n, TokenKind::TABLE_KW),
+        ObjectType::ObjectSequence => emit_relation_rename(e, n, TokenKind::SEQUENCE_KW),
+        ObjectType::ObjectView => emit_relation_rename(e, n, TokenKind::VIEW_KW),
+        ObjectType::ObjectIndex => emit_relation_rename(e, n, TokenKind::INDEX_KW),
+        ObjectType::ObjectMatview => {
             e.token(TokenKind::MATERIALIZED_KW);
             e.space();
-            e.token(TokenKind::VIEW_KW);
+            emit_relation_rename(e, n, TokenKind::VIEW_KW);
+        }
+        ObjectType::ObjectForeignTable => {
+            e.token(TokenKind::FOREIGN_KW);
+            e.space();
+            emit_relation_rename(e, n, TokenKind::TABLE_KW);
+        }
+
+        ObjectType::ObjectColumn => {
+            emit_relation_type_keyword(e, n.relation_type);
+            e.space();
+            emit_if_exists(e, n);
+            emit_relation(e, n);
+            e.space();
+            e.token(TokenKind::RENAME_KW);
+            e.space();
+            e.token(TokenKind::COLUMN_KW);
+            e.space();
+            emit_subname_to_newname(e, n);
+        }
+        ObjectType::ObjectTabconstraint => {
+            emit_relation_type_keyword(e, n.relation_type);
+            e.space();
+            emit_if_exists(e, n);
+            emit_relation(e, n);
+            e.space();
+            e.token(TokenKind::RENAME_KW);
+            e.space();
+            e.token(TokenKind::CONSTRAINT_KW);
+            e.space();
+            emit_subname_to_newname(e, n);
+        }
+        ObjectType::ObjectDomconstraint => {
+            e.token(TokenKind::DOMAIN_KW);
+            e.space();
+            emit_object(e, n);
+            e.space();
+            e.token(TokenKind::RENAME_KW);
+            e.space();
+            e.token(TokenKind::CONSTRAINT_KW);
+            e.space();
+            emit_subname_to_newname(e, n);
+        }
+
+        ObjectType::ObjectDomain => emit_object_rename(e, n, TokenKind::DOMAIN_KW),
+        ObjectType::ObjectFunction => emit_object_rename(e, n, TokenKind::FUNCTION_KW),
+        ObjectType::ObjectProcedure => emit_object_rename(e, n, TokenKind::PROCEDURE_KW),
+        ObjectType::ObjectAggregate => emit_object_rename(e, n, TokenKind::AGGREGATE_KW),
+        ObjectType::ObjectCollation => emit_object_rename(e, n, TokenKind::COLLATION_KW),
+        ObjectType::ObjectConversion => emit_object_rename(e, n, TokenKind::CONVERSION_KW),
+        ObjectType::ObjectStatisticExt => emit_object_rename(e, n, TokenKind::STATISTICS_KW),
+        ObjectType::ObjectType => emit_object_rename(e, n, TokenKind::TYPE_KW),
+        ObjectType::ObjectLanguage => emit_subname_rename(e, n, TokenKind::LANGUAGE_KW),
+        ObjectType::ObjectRoutine => emit_object_rename(e, n, TokenKind::ROUTINE_KW),
+        ObjectType::ObjectOpclass => emit_opclass_or_opfamily_rename(e, n, TokenKind::CLASS_KW),
+        ObjectType::ObjectOpfamily => emit_opclass_or_opfamily_rename(e, n, TokenKind::FAMILY_KW),
+
+        ObjectType::ObjectTsparser => emit_text_search_rename(e, n, TokenKind::PARSER_KW),
+        ObjectType::ObjectTsdictionary => emit_text_search_rename(e, n, TokenKind::DICTIONARY_KW),
+        ObjectType::ObjectTstemplate => emit_text_search_rename(e, n, TokenKind::TEMPLATE_KW),
+        ObjectType::ObjectTsconfiguration => {
+            emit_text_search_rename(e, n, TokenKind::CONFIGURATION_KW)
+        }
+
+        ObjectType::ObjectDatabase => emit_subname_rename(e, n, TokenKind::DATABASE_KW),
+        ObjectType::ObjectSchema => emit_subname_rename(e, n, TokenKind::SCHEMA_KW),
+        ObjectType::ObjectPublication => emit_subname_rename(e, n, TokenKind::PUBLICATION_KW),
+        ObjectType::ObjectSubscription => emit_subname_rename(e, n, TokenKind::SUBSCRIPTION_KW),
+        ObjectType::ObjectForeignServer => emit_subname_rename(e, n, TokenKind::SERVER_KW),
+        ObjectType::ObjectTablespace => emit_subname_rename(e, n, TokenKind::TABLESPACE_KW),
+        // ALTER ROLE/GROUP/USER all normalize to OBJECT_ROLE in the parsed
+        // tree - there's no way to recover which spelling the user wrote.
+        ObjectType::ObjectRole => emit_subname_rename(e, n, TokenKind::ROLE_KW),
+        ObjectType::ObjectEventTrigger => {
+            e.token(TokenKind::EVENT_KW);
+            e.space();
+            emit_subname_rename(e, n, TokenKind::TRIGGER_KW);
         }
-        _ => e.token(TokenKind::TABLE_KW), // default fallback
+        ObjectType::ObjectFdw => {
+            e.token(TokenKind::FOREIGN_KW);
+            e.space();
+            e.token(TokenKind::DATA_KW);
+            e.space();
+            emit_subname_rename(e, n, TokenKind::WRAPPER_KW);
+        }
+
+        ObjectType::ObjectPolicy => emit_on_relation_rename(e, n, TokenKind::POLICY_KW),
+        ObjectType::ObjectRule => emit_on_relation_rename(e, n, TokenKind::RULE_KW),
+        ObjectType::ObjectTrigger => emit_on_relation_rename(e, n, TokenKind::TRIGGER_KW),
+
+        ObjectType::ObjectAttribute => {
+            e.token(TokenKind::TYPE_KW);
+            e.space();
+            emit_relation(e, n);
+            e.space();
+            e.token(TokenKind::RENAME_KW);
+            e.space();
+            e.token(TokenKind::ATTRIBUTE_KW);
+            e.space();
+            emit_subname_to_newname(e, n);
+            match n.behavior {
+                DROP_CASCADE => {
+                    e.space();
+                    e.token(TokenKind::CASCADE_KW);
+                }
+                DROP_RESTRICT => {}
+                _ => {}
+            }
+        }
+
+        // Every `ObjectType` Postgres' grammar can actually attach to a
+        // `RenameStmt` is handled above; anything else would mean
+        // `pgt_query` parsed a form this module doesn't know about yet.
+        other => panic!("emit_rename_stmt: unhandled ObjectType {other:?}"),
     }
 
+    e.token(TokenKind::SEMICOLON);
+
+    e.group_end();
+}
+
+/// `ALTER <keyword> [IF EXISTS] relation RENAME TO newname` - the shape
+/// shared by `TABLE`, `SEQUENCE`, `VIEW`, `INDEX`, ...
+fn emit_relation_rename(e: &mut EventEmitter, n: &RenameStmt, keyword: TokenKind) {
+    e.token(keyword);
+    e.space();
+    emit_if_exists(e, n);
+    emit_relation(e, n);
+    e.space();
+    emit_rename_to(e, n);
+}
+
+/// `ALTER <keyword> object RENAME TO newname` - the shape shared by
+/// `FUNCTION`, `AGGREGATE`, `COLLATION`, `TYPE`, ..., whose name is carried
+/// in `object` rather than `relation`.
+fn emit_object_rename(e: &mut EventEmitter, n: &RenameStmt, keyword: TokenKind) {
+    e.token(keyword);
+    e.space();
+    emit_object(e, n);
+    e.space();
+    emit_rename_to(e, n);
+}
+
+/// `ALTER OPERATOR CLASS|FAMILY name USING access_method RENAME TO newname`
+/// - `object` carries the access method as its first element, followed by
+/// the (possibly qualified) class/family name, per Postgres' grammar.
+fn emit_opclass_or_opfamily_rename(e: &mut EventEmitter, n: &RenameStmt, keyword: TokenKind) {
+    e.token(TokenKind::OPERATOR_KW);
+    e.space();
+    e.token(keyword);
+    e.space();
+
+    if let Some(pgt_query::NodeEnum::List(list)) = n.object.as_ref().and_then(|o| o.node.as_ref())
+    {
+        if let [access_method, name @ ..] = list.items.as_slice() {
+            super::emit_dot_separated_list(e, name);
+            e.space();
+            e.token(TokenKind::USING_KW);
+            e.space();
+            if let Some(pgt_query::NodeEnum::String(s)) = access_method.node.as_ref() {
+                super::emit_identifier_maybe_quoted(e, &s.sval);
+            }
+            e.space();
+        }
+    }
+
+    emit_rename_to(e, n);
+}
+
+/// `ALTER TEXT SEARCH <keyword> object RENAME TO newname`.
+fn emit_text_search_rename(e: &mut EventEmitter, n: &RenameStmt, keyword: TokenKind) {
+    e.token(TokenKind::TEXT_KW);
+    e.space();
+    e.token(TokenKind::SEARCH_KW);
+    e.space();
+    emit_object_rename(e, n, keyword);
+}
+
+/// `ALTER <keyword> name RENAME TO newname` - objects named by a single
+/// unqualified identifier. Databases, schemas, roles and tablespaces carry it
+/// in `subname`; servers, publications, subscriptions, languages, event
+/// triggers and foreign data wrappers carry it as a `String` in `object`,
+/// which is read when `subname` is empty.
+fn emit_subname_rename(e: &mut EventEmitter, n: &RenameStmt, keyword: TokenKind) {
+    e.token(keyword);
+    e.space();
+    let name = match n.object.as_ref().and_then(|o| o.node.as_ref()) {
+        Some(pgt_query::NodeEnum::String(s)) if n.subname.is_empty() => s.sval.clone(),
+        _ => n.subname.clone(),
+    };
+    e.token(TokenKind::IDENT(name.into()));
+    e.space();
+    emit_rename_to(e, n);
+}
+
+/// `ALTER <keyword> subname ON relation RENAME TO newname` - `POLICY`,
+/// `RULE`, and `TRIGGER`, which are named relative to the table they live on.
+fn emit_on_relation_rename(e: &mut EventEmitter, n: &RenameStmt, keyword: TokenKind) {
+    e.token(keyword);
+    e.space();
+    e.token(TokenKind::IDENT(n.subname.clone().into()));
+    e.space();
+    e.token(TokenKind::ON_KW);
+    e.space();
+    emit_relation(e, n);
+    e.space();
+    emit_rename_to(e, n);
+}
+
+fn emit_if_exists(e: &mut EventEmitter, n: &RenameStmt) {
     if n.missing_ok {
-        e.space();
         e.token(TokenKind::IF_KW);
         e.space();
         e.token(TokenKind::EXISTS_KW);
+        e.space();
     }
+}
 
-    e.space();
-
-    // Different object types use different fields for the name:
-    // - TABLE, VIEW, INDEX, etc. use 'relation' field (RangeVar)
-    // - DATABASE, SCHEMA, etc. use 'subname' field (string)
-    // - COLUMN uses both 'relation' and 'subname'
+fn emit_relation(e: &mut EventEmitter, n: &RenameStmt) {
     if let Some(ref relation) = n.relation {
         super::emit_range_var(e, relation);
+    }
+}
 
-        // For COLUMN renames, the column name is in subname
-        if n.rename_type == ObjectType::ObjectColumn as i32 && !n.subname.is_empty() {
-            e.space();
-            e.token(TokenKind::IDENT(n.subname.clone()));
-        }
-    } else if !n.subname.is_empty() {
-        // DATABASE, SCHEMA, etc. use subname directly
-        e.token(TokenKind::IDENT(n.subname.clone()));
+fn emit_object(e: &mut EventEmitter, n: &RenameStmt) {
+    let Some(ref object) = n.object else {
+        return;
+    };
+
+    // Most of `object`'s callers here (DOMAIN, TYPE, COLLATION, CONVERSION,
+    // STATISTICS, the TEXT SEARCH objects) carry a plain qualified name - a
+    // `List` of `String`s - rather than a node kind `emit_node` dispatches on
+    // its own. FUNCTION/PROCEDURE/AGGREGATE carry an `ObjectWithArgs`, which
+    // *is* dispatched, so those fall through to the general case below.
+    if let Some(pgt_query::NodeEnum::List(list)) = object.node.as_ref() {
+        super::emit_dot_separated_list(e, &list.items);
+    } else {
+        super::emit_node(object, e);
     }
+}
 
+/// `subname TO newname` - used after the caller has already emitted its own
+/// `RENAME COLUMN`/`RENAME CONSTRAINT`/`RENAME ATTRIBUTE` keyword, so (unlike
+/// [`emit_rename_to`]) this doesn't emit another `RENAME`.
+fn emit_subname_to_newname(e: &mut EventEmitter, n: &RenameStmt) {
+    e.token(TokenKind::IDENT(n.subname.clone().into()));
     e.space();
+    e.token(TokenKind::TO_KW);
+    e.space();
+    e.token(TokenKind::IDENT(n.newname.clone().into()));
+}
+
+/// `RENAME TO newname` - the simple, common case where nothing else sits
+/// between the object being renamed and the new name.
+fn emit_rename_to(e: &mut EventEmitter, n: &RenameStmt) {
     e.token(TokenKind::RENAME_KW);
     e.space();
     e.token(TokenKind::TO_KW);
     e.space();
-    e.token(TokenKind::IDENT(n.newname.clone()));
-
-    e.token(TokenKind::SEMICOLON);
+    e.token(TokenKind::IDENT(n.newname.clone().into()));
+}
 
-    e.group_end();
+/// The `ALTER <keyword>` object-type keyword for `RENAME COLUMN`/`RENAME
+/// CONSTRAINT`, which can target a table, view, materialized view, or
+/// foreign table (`relation_type`, distinct from `rename_type` which is
+/// `OBJECT_COLUMN`/`OBJECT_TABCONSTRAINT` for all of them).
+fn emit_relation_type_keyword(e: &mut EventEmitter, relation_type: i32) {
+    match ObjectType::try_from(relation_type).unwrap_or(ObjectType::Undefined) {
+        ObjectType::ObjectView => e.token(TokenKind::VIEW_KW),
+        ObjectType::ObjectForeignTable => {
+            e.token(TokenKind::FOREIGN_KW);
+            e.space();
+            e.token(TokenKind::TABLE_KW);
+        }
+        ObjectType::ObjectMatview => {
+            e.token(TokenKind::MATERIALIZED_KW);
+            e.space();
+            e.token(TokenKind::VIEW_KW);
+        }
+        ObjectType::ObjectSequence => e.token(TokenKind::SEQUENCE_KW),
+        // TABLE, and everything else this form doesn't distinguish.
+        _ => e.token(TokenKind::TABLE_KW),
+    }
 }
@@ -1,15 +1,25 @@
 use pgt_query::protobuf::ResTarget;
+use pgt_query::Node;
 
 use crate::TokenKind;
-use crate::emitter::{EventEmitter, GroupKind};
+use crate::emitter::{EventEmitter, GroupKind, LineType};
 
 use super::emit_node;
+use super::node_list::emit_comma_separated_list;
+use super::string::emit_identifier_maybe_quoted_at;
 
 pub(super) fn emit_res_target(e: &mut EventEmitter, n: &ResTarget) {
-    e.group_start(GroupKind::ResTarget);
+    if n.location >= 0 {
+        e.group_start_at(GroupKind::ResTarget, n.location);
+    } else {
+        e.group_start(GroupKind::ResTarget);
+    }
 
     if !n.name.is_empty() {
-        e.token(TokenKind::IDENT(n.name.clone()));
+        // `ResTarget` carries one `location` for the whole target, not a
+        // separate one for `name` - close enough to attribute `name`'s span
+        // to, since pgt_query doesn't give us anything finer-grained here.
+        emit_identifier_maybe_quoted_at(e, &n.name, n.location);
         for i in &n.indirection {
             if !matches!(i.node, Some(pgt_query::protobuf::node::Node::AIndices(_))) {
                 e.token(TokenKind::DOT);
@@ -17,7 +27,7 @@ pub(super) fn emit_res_target(e: &mut EventEmitter, n: &ResTarget) {
             emit_node(i, e);
         }
         e.space();
-        e.token(TokenKind::IDENT("=".to_string()));
+        e.token(TokenKind::IDENT("=".into()));
         e.space();
     }
     if let Some(ref val) = n.val {
@@ -26,3 +36,44 @@ pub(super) fn emit_res_target(e: &mut EventEmitter, n: &ResTarget) {
 
     e.group_end();
 }
+
+/// Emit just the column name part of a `ResTarget` used as an INSERT column
+/// list entry, e.g. the `foo` in `INSERT INTO t (foo) VALUES (...)`.
+pub(super) fn emit_column_name(e: &mut EventEmitter, n: &ResTarget) {
+    super::string::emit_identifier_maybe_quoted(e, &n.name);
+}
+
+/// Emit a `ResTarget` as an `UPDATE`/`ON CONFLICT DO UPDATE` assignment,
+/// e.g. `foo = val`.
+pub(super) fn emit_set_clause(e: &mut EventEmitter, n: &ResTarget) {
+    super::string::emit_identifier_maybe_quoted(e, &n.name);
+    e.space();
+    e.token(TokenKind::IDENT("=".into()));
+    e.space();
+    if let Some(ref val) = n.val {
+        emit_node(val, e);
+    }
+}
+
+/// Emit a `RETURNING` clause's comma-separated list of `ResTarget`s, shared
+/// by `INSERT`/`UPDATE`/`DELETE` - all three carry a `returning_list` of the
+/// same shape, so routing them all through `emit_res_target` here keeps a
+/// `RETURNING id AS new_id` formatted the same regardless of which statement
+/// it trails. `RETURNING` itself sits on a `SoftOrSpace` line so a long
+/// projection list wraps the same way the rest of the formatter does.
+pub(super) fn emit_returning_list(e: &mut EventEmitter, returning_list: &[Node]) {
+    if returning_list.is_empty() {
+        return;
+    }
+
+    e.line(LineType::SoftOrSpace);
+    e.token(TokenKind::RETURNING_KW);
+    e.space();
+    emit_comma_separated_list(e, returning_list, |node, e| {
+        if let Some(pgt_query::NodeEnum::ResTarget(res_target)) = node.node.as_ref() {
+            emit_res_target(e, res_target);
+        } else {
+            emit_node(node, e);
+        }
+    });
+}
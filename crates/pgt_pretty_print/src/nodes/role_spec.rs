@@ -0,0 +1,23 @@
+use pgt_query::protobuf::{RoleSpec, RoleSpecType};
+
+use crate::TokenKind;
+use crate::emitter::EventEmitter;
+
+use super::string::emit_identifier_maybe_quoted;
+
+pub(super) fn emit_role_spec(e: &mut EventEmitter, n: &RoleSpec) {
+    match RoleSpecType::try_from(n.roletype).unwrap_or(RoleSpecType::Undefined) {
+        RoleSpecType::RolespecCstring => emit_identifier_maybe_quoted(e, &n.rolename),
+        RoleSpecType::RolespecCurrentUser => {
+            e.token(TokenKind::KEYWORD("CURRENT_USER".into()))
+        }
+        RoleSpecType::RolespecSessionUser => {
+            e.token(TokenKind::KEYWORD("SESSION_USER".into()))
+        }
+        RoleSpecType::RolespecCurrentRole => {
+            e.token(TokenKind::KEYWORD("CURRENT_ROLE".into()))
+        }
+        RoleSpecType::RolespecPublic => e.token(TokenKind::KEYWORD("PUBLIC".into())),
+        RoleSpecType::Undefined => emit_identifier_maybe_quoted(e, &n.rolename),
+    }
+}
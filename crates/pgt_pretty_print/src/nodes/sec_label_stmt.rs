@@ -7,16 +7,16 @@ use pgt_query::protobuf::{ObjectType, SecLabelStmt};
 pub(super) fn emit_sec_label_stmt(e: &mut EventEmitter, n: &SecLabelStmt) {
     e.group_start(GroupKind::SecLabelStmt);
 
-    e.token(TokenKind::IDENT("SECURITY".to_string()));
+    e.token(TokenKind::KEYWORD("SECURITY".into()));
     e.space();
-    e.token(TokenKind::IDENT("LABEL".to_string()));
+    e.token(TokenKind::KEYWORD("LABEL".into()));
 
     // Emit FOR provider if present
     if !n.provider.is_empty() {
         e.space();
         e.token(TokenKind::FOR_KW);
         e.space();
-        e.token(TokenKind::IDENT(n.provider.clone()));
+        e.token(TokenKind::IDENT(n.provider.clone().into()));
     }
 
     // Emit ON object_type object
@@ -47,7 +47,7 @@ pub(super) fn emit_sec_label_stmt(e: &mut EventEmitter, n: &SecLabelStmt) {
         _ => "TABLE", // Default fallback
     };
 
-    e.token(TokenKind::IDENT(objtype_str.to_string()));
+    e.token(TokenKind::KEYWORD(objtype_str.into()));
     e.space();
 
     // Emit object name
@@ -59,7 +59,7 @@ pub(super) fn emit_sec_label_stmt(e: &mut EventEmitter, n: &SecLabelStmt) {
     e.space();
     e.token(TokenKind::IS_KW);
     e.space();
-    e.token(TokenKind::IDENT(format!("'{}'", n.label)));
+    e.token(TokenKind::IDENT(format!("'{}'", n.label).into()));
 
     e.token(TokenKind::SEMICOLON);
 
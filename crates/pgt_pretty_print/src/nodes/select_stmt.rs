@@ -1,7 +1,8 @@
+use pgt_query::Node;
 use pgt_query::protobuf::SelectStmt;
 
 use crate::TokenKind;
-use crate::emitter::{EventEmitter, GroupKind, LineType};
+use crate::emitter::{EventEmitter, GroupKind, LineType, SemicolonPolicy};
 
 use super::node_list::emit_comma_separated_list;
 
@@ -9,11 +10,15 @@ pub(super) fn emit_select_stmt(e: &mut EventEmitter, n: &SelectStmt) {
     emit_select_stmt_impl(e, n, true);
 }
 
+/// Used when a `SelectStmt` is nested inside another statement (a CTE
+/// body, a set-operation operand, an `INSERT ... SELECT` body, ...) - a
+/// semicolon is never syntactically valid there, regardless of
+/// [`SemicolonPolicy`].
 pub(super) fn emit_select_stmt_no_semicolon(e: &mut EventEmitter, n: &SelectStmt) {
     emit_select_stmt_impl(e, n, false);
 }
 
-fn emit_select_stmt_impl(e: &mut EventEmitter, n: &SelectStmt, with_semicolon: bool) {
+fn emit_select_stmt_impl(e: &mut EventEmitter, n: &SelectStmt, semicolon_eligible: bool) {
     e.group_start(GroupKind::SelectStmt);
 
     // Emit WITH clause (Common Table Expressions) if present
@@ -51,7 +56,7 @@ fn emit_select_stmt_impl(e: &mut EventEmitter, n: &SelectStmt, with_semicolon: b
             emit_select_stmt_no_semicolon(e, rarg);
         }
 
-        if with_semicolon {
+        if semicolon_eligible && e.config().semicolon_policy == SemicolonPolicy::Always {
             e.token(TokenKind::SEMICOLON);
         }
 
@@ -64,14 +69,12 @@ fn emit_select_stmt_impl(e: &mut EventEmitter, n: &SelectStmt, with_semicolon: b
         e.token(TokenKind::VALUES_KW);
         e.space();
 
-        // Emit each row of values
-        emit_comma_separated_list(e, &n.values_lists, |row, e| {
-            e.token(TokenKind::L_PAREN);
-            super::emit_node(row, e);
-            e.token(TokenKind::R_PAREN);
-        });
+        // Emit each row of values, one row per `GroupKind::ValuesRow` group
+        // so a large multi-row INSERT breaks one row per line rather than
+        // breaking mid-row.
+        emit_comma_separated_list(e, &n.values_lists, emit_values_row);
 
-        if with_semicolon {
+        if semicolon_eligible && e.config().semicolon_policy == SemicolonPolicy::Always {
             e.token(TokenKind::SEMICOLON);
         }
     } else {
@@ -163,10 +166,29 @@ fn emit_select_stmt_impl(e: &mut EventEmitter, n: &SelectStmt, with_semicolon: b
             super::emit_node(limit_offset, e);
         }
 
-        if with_semicolon {
+        if semicolon_eligible && e.config().semicolon_policy == SemicolonPolicy::Always {
             e.token(TokenKind::SEMICOLON);
         }
     }
 
     e.group_end();
 }
+
+/// Emit one row of a `VALUES (...), (...), ...` list.
+///
+/// A row's `Node` wraps a `List` - not a standalone node kind `emit_node`
+/// dispatches on its own (see `nodes::emit_node`'s doc comment) - so its
+/// items are unwrapped and emitted comma-separated here.
+fn emit_values_row(row: &Node, e: &mut EventEmitter) {
+    e.group_start(GroupKind::ValuesRow);
+    e.token(TokenKind::L_PAREN);
+
+    if let Some(pgt_query::NodeEnum::List(list)) = row.node.as_ref() {
+        emit_comma_separated_list(e, &list.items, super::emit_node);
+    } else {
+        super::emit_node(row, e);
+    }
+
+    e.token(TokenKind::R_PAREN);
+    e.group_end();
+}
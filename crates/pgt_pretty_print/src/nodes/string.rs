@@ -3,11 +3,12 @@ use pgt_query::protobuf::String;
 use crate::{
     TokenKind,
     emitter::{EventEmitter, GroupKind},
+    keywords::{self, KeywordCategory},
 };
 
 pub(super) fn emit_string(e: &mut EventEmitter, n: &String) {
     e.group_start(GroupKind::String);
-    e.token(TokenKind::IDENT(n.sval.clone()));
+    e.token(TokenKind::IDENT(n.sval.clone().into()));
     e.group_end();
 }
 
@@ -15,7 +16,7 @@ pub(super) fn emit_string_literal(e: &mut EventEmitter, n: &String) {
     e.group_start(GroupKind::String);
     // Escape single quotes by doubling them (PostgreSQL string literal syntax)
     let escaped = n.sval.replace('\'', "''");
-    e.token(TokenKind::IDENT(format!("'{}'", escaped)));
+    e.token(TokenKind::IDENT(format!("'{}'", escaped).into()));
     e.group_end();
 }
 
@@ -28,15 +29,15 @@ pub(super) fn emit_string_identifier(e: &mut EventEmitter, n: &String) {
 pub(super) fn emit_identifier(e: &mut EventEmitter, n: &str) {
     // Escape double quotes by doubling them (PostgreSQL identifier syntax)
     let escaped = n.replace('"', "\"\"");
-    e.token(TokenKind::IDENT(format!("\"{}\"", escaped)));
+    e.token(TokenKind::IDENT(format!("\"{}\"", escaped).into()));
 }
 
 /// Emit an identifier, adding quotes only if necessary.
 /// Quotes are needed if:
 /// - Contains special characters (space, comma, quotes, etc.)
-/// - Is a SQL keyword
 /// - Starts with a digit
 /// - Contains uppercase letters (to preserve case)
+/// - Is a reserved or type/function-name keyword (see [`keywords::classify`])
 /// Note: Empty strings are emitted as plain identifiers (not quoted)
 pub(super) fn emit_identifier_maybe_quoted(e: &mut EventEmitter, n: &str) {
     // Don't emit empty identifiers at all
@@ -47,7 +48,29 @@ pub(super) fn emit_identifier_maybe_quoted(e: &mut EventEmitter, n: &str) {
     if needs_quoting(n) {
         emit_identifier(e, n);
     } else {
-        e.token(TokenKind::IDENT(n.to_string()));
+        e.token(TokenKind::IDENT(n.to_string().into()));
+    }
+}
+
+/// Like [`emit_identifier_maybe_quoted`], but ties the emitted token back to
+/// `location`, the byte offset `n` started at in the original source, so
+/// [`crate::renderer::Renderer::render_with_position_map`] can map it back.
+/// The span's length is conservatively `n`'s own length - quoting may add a
+/// couple of bytes the source didn't have (or vice versa), but `location` is
+/// the only byte offset `pgt_query` gives us for most identifier-bearing
+/// nodes.
+pub(super) fn emit_identifier_maybe_quoted_at(e: &mut EventEmitter, n: &str, location: i32) {
+    if n.is_empty() || location < 0 {
+        emit_identifier_maybe_quoted(e, n);
+        return;
+    }
+
+    let span = (location as usize)..(location as usize + n.len());
+    if needs_quoting(n) {
+        let escaped = n.replace('"', "\"\"");
+        e.token_at(TokenKind::IDENT(format!("\"{}\"", escaped).into()), span);
+    } else {
+        e.token_at(TokenKind::IDENT(n.to_string().into()), span);
     }
 }
 
@@ -72,66 +95,11 @@ fn needs_quoting(s: &str) -> bool {
         return true;
     }
 
-    // Check if it's a SQL keyword (simplified list of common ones)
-    // In a real implementation, this would check against the full keyword list
-    const KEYWORDS: &[&str] = &[
-        "select",
-        "from",
-        "where",
-        "insert",
-        "update",
-        "delete",
-        "create",
-        "drop",
-        "alter",
-        "table",
-        "index",
-        "view",
-        "schema",
-        "database",
-        "user",
-        "role",
-        "grant",
-        "revoke",
-        "with",
-        "as",
-        "on",
-        "in",
-        "into",
-        "values",
-        "set",
-        "default",
-        "null",
-        "not",
-        "and",
-        "or",
-        "between",
-        "like",
-        "ilike",
-        "case",
-        "when",
-        "then",
-        "else",
-        "end",
-        "join",
-        "left",
-        "right",
-        "inner",
-        "outer",
-        "cross",
-        "union",
-        "intersect",
-        "except",
-        "order",
-        "group",
-        "having",
-        "limit",
-        "offset",
-        "by",
-        "for",
-        "to",
-        "of",
-    ];
-
-    KEYWORDS.contains(&s.to_lowercase().as_str())
+    // Only RESERVED and TYPE_FUNC_NAME keywords are invalid as a bare
+    // identifier here; UNRESERVED and COL_NAME keywords are fine unquoted
+    // wherever pgt_query already parsed them as one.
+    matches!(
+        keywords::classify(s),
+        Some(KeywordCategory::Reserved) | Some(KeywordCategory::TypeFuncName)
+    )
 }
@@ -38,7 +38,7 @@ pub(super) fn emit_type_name(e: &mut EventEmitter, n: &TypeName) {
                 if i > 0 {
                     e.token(TokenKind::DOT);
                 }
-                e.token(TokenKind::IDENT(part.clone()));
+                e.token(TokenKind::IDENT(part.clone().into()));
             }
             // Already emitted, return early after modifiers
             emit_type_modifiers(e, n);
@@ -47,7 +47,7 @@ pub(super) fn emit_type_name(e: &mut EventEmitter, n: &TypeName) {
             return;
         };
 
-        e.token(TokenKind::IDENT(type_name));
+        e.token(TokenKind::KEYWORD(type_name.into()));
     }
 
     // Add type modifiers if present (e.g., VARCHAR(255))
@@ -60,16 +60,106 @@ pub(super) fn emit_type_name(e: &mut EventEmitter, n: &TypeName) {
 }
 
 fn emit_type_modifiers(e: &mut EventEmitter, n: &TypeName) {
-    if !n.typmods.is_empty() {
-        // TODO: Handle special INTERVAL type modifiers
+    if n.typmods.is_empty() {
+        return;
+    }
+
+    if is_interval_type(n) {
+        emit_interval_type_modifiers(e, n);
+        return;
+    }
+
+    e.token(TokenKind::L_PAREN);
+    emit_comma_separated_list(e, &n.typmods, |node, emitter| {
+        super::emit_node(node, emitter)
+    });
+    e.token(TokenKind::R_PAREN);
+}
+
+fn is_interval_type(n: &TypeName) -> bool {
+    n.names.last().is_some_and(|node| {
+        matches!(
+            &node.node,
+            Some(pgt_query::NodeEnum::String(s)) if s.sval.eq_ignore_ascii_case("interval")
+        )
+    })
+}
+
+/// Field codes `INTERVAL_MASK` shifts by, mirroring `src/include/utils/datetime.h`.
+const INTERVAL_MONTH_FIELD: i32 = 1;
+const INTERVAL_YEAR_FIELD: i32 = 2;
+const INTERVAL_DAY_FIELD: i32 = 3;
+const INTERVAL_HOUR_FIELD: i32 = 10;
+const INTERVAL_MINUTE_FIELD: i32 = 11;
+const INTERVAL_SECOND_FIELD: i32 = 12;
+
+const fn interval_mask(field: i32) -> i32 {
+    1 << field
+}
+
+/// Decode an `INTERVAL`'s typmods and emit the field-qualifier phrase (e.g.
+/// `DAY TO SECOND`) plus an optional `(<precision>)`. In the raw parse tree
+/// the first typmod is the range (an `INTERVAL_MASK` field-qualifier bitmask)
+/// and the second, when present, is the precision.
+fn emit_interval_type_modifiers(e: &mut EventEmitter, n: &TypeName) {
+    let Some(range) = n.typmods.first().and_then(typmod_int) else {
+        return;
+    };
+
+    let precision = n.typmods.get(1).and_then(typmod_int);
+
+    let year = interval_mask(INTERVAL_YEAR_FIELD);
+    let month = interval_mask(INTERVAL_MONTH_FIELD);
+    let day = interval_mask(INTERVAL_DAY_FIELD);
+    let hour = interval_mask(INTERVAL_HOUR_FIELD);
+    let minute = interval_mask(INTERVAL_MINUTE_FIELD);
+    let second = interval_mask(INTERVAL_SECOND_FIELD);
+
+    let phrase = match range {
+        r if r == year => Some("YEAR"),
+        r if r == month => Some("MONTH"),
+        r if r == day => Some("DAY"),
+        r if r == hour => Some("HOUR"),
+        r if r == minute => Some("MINUTE"),
+        r if r == second => Some("SECOND"),
+        r if r == year | month => Some("YEAR TO MONTH"),
+        r if r == day | hour => Some("DAY TO HOUR"),
+        r if r == day | hour | minute => Some("DAY TO MINUTE"),
+        r if r == day | hour | minute | second => Some("DAY TO SECOND"),
+        r if r == hour | minute => Some("HOUR TO MINUTE"),
+        r if r == hour | minute | second => Some("HOUR TO SECOND"),
+        r if r == minute | second => Some("MINUTE TO SECOND"),
+        // The full range (no field qualifier) carries no restriction and
+        // isn't printed.
+        _ => None,
+    };
+
+    if let Some(phrase) = phrase {
+        e.space();
+        e.token(TokenKind::KEYWORD(phrase.into()));
+    }
+
+    if let Some(precision) = precision {
         e.token(TokenKind::L_PAREN);
-        emit_comma_separated_list(e, &n.typmods, |node, emitter| {
-            super::emit_node(node, emitter)
-        });
+        e.token(TokenKind::IDENT(precision.to_string().into()));
         e.token(TokenKind::R_PAREN);
     }
 }
 
+/// Pull the literal integer value out of a typmod node, however
+/// `pgt_query` happened to wrap it (a bare `Integer`, or an `A_Const`
+/// carrying one).
+fn typmod_int(node: &pgt_query::Node) -> Option<i32> {
+    match node.node.as_ref()? {
+        pgt_query::NodeEnum::Integer(i) => Some(i.ival),
+        pgt_query::NodeEnum::AConst(a) => match a.val.as_ref()? {
+            pgt_query::protobuf::a_const::Val::Ival(i) => Some(i.ival),
+            _ => None,
+        },
+        _ => None,
+    }
+}
+
 fn emit_array_bounds(e: &mut EventEmitter, n: &TypeName) {
     // Emit array bounds (e.g., [] or [10])
     for bound in &n.array_bounds {
@@ -79,7 +169,7 @@ fn emit_array_bounds(e: &mut EventEmitter, n: &TypeName) {
                 e.token(TokenKind::R_BRACK);
             } else {
                 e.token(TokenKind::L_BRACK);
-                e.token(TokenKind::IDENT(int_bound.ival.to_string()));
+                e.token(TokenKind::IDENT(int_bound.ival.to_string().into()));
                 e.token(TokenKind::R_BRACK);
             }
         }
@@ -6,6 +6,7 @@ use crate::{
 };
 
 use super::node_list::emit_comma_separated_list;
+use super::string::emit_identifier_maybe_quoted;
 
 pub(super) fn emit_view_stmt(e: &mut EventEmitter, n: &ViewStmt) {
     e.group_start(GroupKind::ViewStmt);
@@ -31,7 +32,13 @@ pub(super) fn emit_view_stmt(e: &mut EventEmitter, n: &ViewStmt) {
     if !n.aliases.is_empty() {
         e.space();
         e.token(TokenKind::L_PAREN);
-        emit_comma_separated_list(e, &n.aliases, super::emit_node);
+        emit_comma_separated_list(e, &n.aliases, |node, e| {
+            if let Some(pgt_query::NodeEnum::String(s)) = &node.node {
+                emit_identifier_maybe_quoted(e, &s.sval);
+            } else {
+                super::emit_node(node, e);
+            }
+        });
         e.token(TokenKind::R_PAREN);
     }
 
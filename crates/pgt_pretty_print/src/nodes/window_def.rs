@@ -1,6 +1,27 @@
 use crate::{TokenKind, emitter::EventEmitter, nodes::node_list::emit_comma_separated_list};
 use pgt_query::protobuf::WindowDef;
 
+// Frame option bits, mirroring PostgreSQL's `FRAMEOPTION_*` flags in
+// `parsenodes.h`. `WindowDef::frame_options` is their bitwise OR.
+const FRAMEOPTION_NONDEFAULT: i32 = 0x00001;
+const FRAMEOPTION_RANGE: i32 = 0x00002;
+const FRAMEOPTION_ROWS: i32 = 0x00004;
+const FRAMEOPTION_GROUPS: i32 = 0x00008;
+const FRAMEOPTION_BETWEEN: i32 = 0x00010;
+const FRAMEOPTION_START_UNBOUNDED_PRECEDING: i32 = 0x00020;
+const FRAMEOPTION_END_UNBOUNDED_PRECEDING: i32 = 0x00040;
+const FRAMEOPTION_START_UNBOUNDED_FOLLOWING: i32 = 0x00080;
+const FRAMEOPTION_END_UNBOUNDED_FOLLOWING: i32 = 0x00100;
+const FRAMEOPTION_START_CURRENT_ROW: i32 = 0x00200;
+const FRAMEOPTION_END_CURRENT_ROW: i32 = 0x00400;
+const FRAMEOPTION_START_OFFSET_PRECEDING: i32 = 0x00800;
+const FRAMEOPTION_END_OFFSET_PRECEDING: i32 = 0x01000;
+const FRAMEOPTION_START_OFFSET_FOLLOWING: i32 = 0x02000;
+const FRAMEOPTION_END_OFFSET_FOLLOWING: i32 = 0x04000;
+const FRAMEOPTION_EXCLUDE_CURRENT_ROW: i32 = 0x08000;
+const FRAMEOPTION_EXCLUDE_GROUP: i32 = 0x10000;
+const FRAMEOPTION_EXCLUDE_TIES: i32 = 0x20000;
+
 // WindowDef is not a NodeEnum type, so we don't use pub(super)
 // It's a helper structure used within FuncCall and SelectStmt
 pub fn emit_window_def(e: &mut EventEmitter, n: &WindowDef) {
@@ -9,7 +30,7 @@ pub fn emit_window_def(e: &mut EventEmitter, n: &WindowDef) {
 
     // If refname is set, this is a reference to a named window
     if !n.refname.is_empty() {
-        e.token(TokenKind::IDENT(n.refname.clone()));
+        e.token(TokenKind::IDENT(n.refname.clone().into()));
         return;
     }
 
@@ -43,13 +64,110 @@ pub fn emit_window_def(e: &mut EventEmitter, n: &WindowDef) {
         });
     }
 
-    // Frame clause (ROWS/RANGE/GROUPS)
-    // frame_options is a bitmap that encodes the frame clause
-    // This is complex - implementing basic support
-    // TODO: Full frame clause implementation with start_offset and end_offset
-    // For now, we skip frame clause emission if frame_options != 0
-    // The default frame options (1058 = RANGE BETWEEN UNBOUNDED PRECEDING AND CURRENT ROW)
-    // are implicit and don't need to be emitted
+    // Frame clause (ROWS/RANGE/GROUPS ... BETWEEN ... AND ... EXCLUDE ...).
+    // The all-default frame (RANGE UNBOUNDED PRECEDING, implicitly CURRENT
+    // ROW, no EXCLUDE) carries no `FRAMEOPTION_NONDEFAULT` bit and is never
+    // printed, matching how Postgres itself elides it in `ruleutils.c`.
+    if n.frame_options & FRAMEOPTION_NONDEFAULT != 0 {
+        if needs_space {
+            e.space();
+        }
+
+        e.token(if n.frame_options & FRAMEOPTION_ROWS != 0 {
+            TokenKind::ROWS_KW
+        } else if n.frame_options & FRAMEOPTION_GROUPS != 0 {
+            TokenKind::GROUPS_KW
+        } else {
+            TokenKind::RANGE_KW
+        });
+        e.space();
+
+        if n.frame_options & FRAMEOPTION_BETWEEN != 0 {
+            e.token(TokenKind::BETWEEN_KW);
+            e.space();
+            emit_frame_bound(e, n.frame_options, n.start_offset.as_deref(), true);
+            e.space();
+            e.token(TokenKind::AND_KW);
+            e.space();
+            emit_frame_bound(e, n.frame_options, n.end_offset.as_deref(), false);
+        } else {
+            emit_frame_bound(e, n.frame_options, n.start_offset.as_deref(), true);
+        }
+
+        if n.frame_options & FRAMEOPTION_EXCLUDE_CURRENT_ROW != 0 {
+            e.space();
+            e.token(TokenKind::EXCLUDE_KW);
+            e.space();
+            e.token(TokenKind::CURRENT_KW);
+            e.space();
+            e.token(TokenKind::ROW_KW);
+        } else if n.frame_options & FRAMEOPTION_EXCLUDE_GROUP != 0 {
+            e.space();
+            e.token(TokenKind::EXCLUDE_KW);
+            e.space();
+            e.token(TokenKind::GROUP_KW);
+        } else if n.frame_options & FRAMEOPTION_EXCLUDE_TIES != 0 {
+            e.space();
+            e.token(TokenKind::EXCLUDE_KW);
+            e.space();
+            e.token(TokenKind::TIES_KW);
+        }
+        // EXCLUDE NO OTHERS is the default and isn't printed either.
+    }
 
     e.token(TokenKind::R_PAREN);
 }
+
+/// Emit one side (`is_start`) of a frame's `BETWEEN ... AND ...` bound, or
+/// the lone bound of a frame with no explicit `BETWEEN`.
+fn emit_frame_bound(
+    e: &mut EventEmitter,
+    frame_options: i32,
+    offset: Option<&pgt_query::Node>,
+    is_start: bool,
+) {
+    let (unbounded_preceding, unbounded_following, current_row, offset_preceding, offset_following) =
+        if is_start {
+            (
+                FRAMEOPTION_START_UNBOUNDED_PRECEDING,
+                FRAMEOPTION_START_UNBOUNDED_FOLLOWING,
+                FRAMEOPTION_START_CURRENT_ROW,
+                FRAMEOPTION_START_OFFSET_PRECEDING,
+                FRAMEOPTION_START_OFFSET_FOLLOWING,
+            )
+        } else {
+            (
+                FRAMEOPTION_END_UNBOUNDED_PRECEDING,
+                FRAMEOPTION_END_UNBOUNDED_FOLLOWING,
+                FRAMEOPTION_END_CURRENT_ROW,
+                FRAMEOPTION_END_OFFSET_PRECEDING,
+                FRAMEOPTION_END_OFFSET_FOLLOWING,
+            )
+        };
+
+    if frame_options & unbounded_preceding != 0 {
+        e.token(TokenKind::UNBOUNDED_KW);
+        e.space();
+        e.token(TokenKind::PRECEDING_KW);
+    } else if frame_options & unbounded_following != 0 {
+        e.token(TokenKind::UNBOUNDED_KW);
+        e.space();
+        e.token(TokenKind::FOLLOWING_KW);
+    } else if frame_options & current_row != 0 {
+        e.token(TokenKind::CURRENT_KW);
+        e.space();
+        e.token(TokenKind::ROW_KW);
+    } else if frame_options & offset_preceding != 0 {
+        if let Some(offset) = offset {
+            super::emit_node(offset, e);
+            e.space();
+        }
+        e.token(TokenKind::PRECEDING_KW);
+    } else if frame_options & offset_following != 0 {
+        if let Some(offset) = offset {
+            super::emit_node(offset, e);
+            e.space();
+        }
+        e.token(TokenKind::FOLLOWING_KW);
+    }
+}
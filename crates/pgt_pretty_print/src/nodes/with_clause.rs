@@ -0,0 +1,87 @@
+use pgt_query::protobuf::{CommonTableExpr, WithClause};
+
+use crate::TokenKind;
+use crate::emitter::{EventEmitter, LineType};
+
+use super::node_list::emit_comma_separated_list;
+use super::string::emit_identifier_maybe_quoted;
+
+/// Emit a `WITH [RECURSIVE] cte1 AS (...), cte2 AS (...)` clause shared by
+/// every statement that can carry CTEs.
+pub(super) fn emit_with_clause(e: &mut EventEmitter, n: &WithClause) {
+    e.token(TokenKind::WITH_KW);
+    e.space();
+
+    if n.recursive {
+        e.token(TokenKind::KEYWORD("RECURSIVE".into()));
+        e.space();
+    }
+
+    e.indent_start();
+    emit_comma_separated_list(e, &n.ctes, |node, e| {
+        if let Some(pgt_query::NodeEnum::CommonTableExpr(cte)) = &node.node {
+            emit_common_table_expr(e, cte);
+        } else {
+            super::emit_node(node, e);
+        }
+    });
+    e.indent_end();
+}
+
+fn emit_common_table_expr(e: &mut EventEmitter, n: &CommonTableExpr) {
+    emit_identifier_maybe_quoted(e, &n.ctename);
+
+    if !n.aliascolnames.is_empty() {
+        e.space();
+        e.token(TokenKind::L_PAREN);
+        emit_comma_separated_list(e, &n.aliascolnames, |node, e| {
+            if let Some(pgt_query::NodeEnum::String(s)) = &node.node {
+                emit_identifier_maybe_quoted(e, &s.sval);
+            } else {
+                super::emit_node(node, e);
+            }
+        });
+        e.token(TokenKind::R_PAREN);
+    }
+
+    e.space();
+    e.token(TokenKind::AS_KW);
+    e.space();
+
+    // CTEMaterialize: 0=default (no keyword), 1=MATERIALIZED, 2=NOT MATERIALIZED
+    match n.ctematerialized {
+        1 => {
+            e.token(TokenKind::MATERIALIZED_KW);
+            e.space();
+        }
+        2 => {
+            e.token(TokenKind::NOT_KW);
+            e.space();
+            e.token(TokenKind::MATERIALIZED_KW);
+            e.space();
+        }
+        _ => {}
+    }
+
+    e.token(TokenKind::L_PAREN);
+    e.indent_start();
+    e.line(LineType::Soft);
+    if let Some(ref query) = n.ctequery {
+        // Nested statements never carry their own trailing semicolon.
+        match query.node.as_ref() {
+            Some(pgt_query::NodeEnum::SelectStmt(stmt)) => {
+                super::emit_select_stmt_no_semicolon(e, stmt)
+            }
+            Some(pgt_query::NodeEnum::InsertStmt(stmt)) => {
+                super::emit_insert_stmt_no_semicolon(e, stmt)
+            }
+            Some(pgt_query::NodeEnum::DeleteStmt(stmt)) => {
+                super::emit_delete_stmt_no_semicolon(e, stmt)
+            }
+            _ => super::emit_node(query, e),
+        }
+    }
+    e.indent_end();
+    e.line(LineType::Soft);
+    e.token(TokenKind::R_PAREN);
+}
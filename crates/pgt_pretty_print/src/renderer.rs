@@ -0,0 +1,604 @@
+use std::borrow::Cow;
+use std::fmt::{self, Write};
+use std::ops::Range;
+
+use crate::emitter::{BreakStyle, Comment, CommentPosition, Event, GroupKind, LineType};
+use crate::token::TokenKind;
+
+/// One entry of a [`Renderer::render_with_source_map`] result: the `[start,
+/// end)` character range a group occupied in the rendered output, the byte
+/// `location` of the AST node it came from, and which kind of group it was.
+pub type SourceMapEntry = (Range<usize>, i32, GroupKind);
+
+/// One entry of a [`Renderer::render_with_position_map`] result: the
+/// `[start, end)` byte range a single spanned token or line occupied in the
+/// rendered output, and the `[start, end)` byte range it came from in the
+/// original source text.
+pub type PositionMapEntry = (Range<usize>, Range<usize>);
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum IndentStyle {
+    Spaces,
+    Tabs,
+}
+
+/// Called by the renderer at every group boundary, letting a downstream
+/// tool key off a group's [`GroupKind`] and the output position it starts
+/// or ends at - e.g. a syntax highlighter wrapping a `GroupKind::AExpr`
+/// region in markup, or a folding-range provider recording the byte span of
+/// every multi-line group. `position` is `self.out.len()` at the moment of
+/// the call, and `out` is the renderer's output buffer itself, so an
+/// implementor that wants to inject surrounding markup can just push it
+/// straight in - it lands exactly where the call happens in the stream.
+///
+/// Object-safe so a caller can box an implementation and swap it in per
+/// [`Renderer::with_annotator`] call; [`Renderer::new`] installs a no-op
+/// default, leaving plain-string formatting unaffected.
+pub trait GroupAnnotator {
+    fn on_group_start(&mut self, out: &mut String, kind: GroupKind, position: usize) {
+        let _ = (out, kind, position);
+    }
+    fn on_group_end(&mut self, out: &mut String, kind: GroupKind, position: usize) {
+        let _ = (out, kind, position);
+    }
+}
+
+/// The annotator [`Renderer::new`] installs - does nothing, so rendering
+/// without an explicit [`GroupAnnotator`] is unaffected.
+struct NoopAnnotator;
+impl GroupAnnotator for NoopAnnotator {}
+
+/// How the renderer cases keyword-class tokens: the `_KW` [`TokenKind`]
+/// variants, and [`TokenKind::KEYWORD`] (boolean literals, normalized type
+/// names, ...). Emitters always build these in upper case; the renderer
+/// applies this policy when it materializes each token to text, so casing
+/// stays a single render-time concern rather than something every emitter
+/// has to get right.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum KeywordCase {
+    Upper,
+    Lower,
+    /// Emit exactly as the emitter built it (currently always upper case).
+    Preserve,
+}
+
+/// How the renderer cases [`TokenKind::IDENT`] tokens - identifiers lifted
+/// verbatim out of the AST (column names, table names, string literals, ...).
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum IdentifierCase {
+    Upper,
+    Lower,
+    /// Emit exactly as the emitter built it (the source's original casing).
+    Preserve,
+}
+
+#[derive(Debug, Clone, Copy)]
+pub struct RenderConfig {
+    pub max_line_length: usize,
+    pub indent_size: usize,
+    pub indent_style: IndentStyle,
+    pub keyword_case: KeywordCase,
+    pub identifier_case: IdentifierCase,
+}
+
+/// Turns an [`Event`] stream into formatted text.
+///
+/// Rendering is Oppen's two-pass pretty-printing algorithm. [`analyze`]
+/// scans the whole event stream once up front, tracking open groups on a
+/// stack, to compute in O(n) total: every group's flat width (and whether it
+/// contains a break that can never be collapsed, a hard line or a comment),
+/// and for every break, the flat width of the content from right after it up
+/// to the next break or the end of its group. [`Self::render_inner`] then
+/// makes a single streaming pass that decides each group's fit against the
+/// column it actually starts at, and - for a group that doesn't fit - either
+/// breaks every one of its [`BreakStyle::Consistent`] breaks unconditionally,
+/// or, for [`BreakStyle::Inconsistent`], breaks only those whose following
+/// content would overflow the margin from the current column.
+pub struct Renderer<'a> {
+    out: &'a mut String,
+    config: RenderConfig,
+    indent: usize,
+    column: usize,
+    /// Set right after a line comment is written; the next token must start
+    /// on a new line no matter what the following `Line` event says.
+    pending_hard_break: bool,
+    /// Set once whitespace (a written space, or a newline plus its indent)
+    /// has been placed and cleared by the next actual token/comment. Lets a
+    /// run of several `Event::Space` in a row - which happens now that
+    /// [`crate::emitter::EventEmitter::token`] inserts its own spacing on
+    /// top of whatever a caller wrote by hand - collapse to a single space
+    /// instead of padding the output.
+    last_was_space: bool,
+    annotator: Box<dyn GroupAnnotator>,
+}
+
+impl<'a> Renderer<'a> {
+    pub fn new(out: &'a mut String, config: RenderConfig) -> Self {
+        Self::with_annotator(out, config, Box::new(NoopAnnotator))
+    }
+
+    /// Like [`Self::new`], but notifies `annotator` at every group boundary
+    /// as rendering walks the event stream. See [`GroupAnnotator`].
+    pub fn with_annotator(
+        out: &'a mut String,
+        config: RenderConfig,
+        annotator: Box<dyn GroupAnnotator>,
+    ) -> Self {
+        Self {
+            out,
+            config,
+            indent: 0,
+            column: 0,
+            pending_hard_break: false,
+            last_was_space: true,
+            annotator,
+        }
+    }
+
+    pub fn render(&mut self, events: Vec<Event>) -> fmt::Result {
+        self.render_inner(events, None, None)
+    }
+
+    /// Like [`Self::render`], but also returns a source map: for every group
+    /// that was started with [`crate::emitter::EventEmitter::group_start_at`],
+    /// the `[start, end)` byte range it occupied in `self.out` together with
+    /// the source location and `GroupKind` it came from.
+    pub fn render_with_source_map(
+        &mut self,
+        events: Vec<Event>,
+    ) -> Result<Vec<SourceMapEntry>, fmt::Error> {
+        let mut source_map = Vec::new();
+        self.render_inner(events, Some(&mut source_map), None)?;
+        Ok(source_map)
+    }
+
+    /// Like [`Self::render`], but also returns a position map: for every
+    /// token or line emitted via [`crate::emitter::EventEmitter::token_at`]
+    /// or [`crate::emitter::EventEmitter::line_at`], the `[start, end)` byte
+    /// range it occupied in `self.out` together with the source byte range
+    /// it was rendered from. Lets callers translate a cursor or selection in
+    /// the formatted output back to the original statement.
+    pub fn render_with_position_map(
+        &mut self,
+        events: Vec<Event>,
+    ) -> Result<Vec<PositionMapEntry>, fmt::Error> {
+        let mut position_map = Vec::new();
+        self.render_inner(events, None, Some(&mut position_map))?;
+        Ok(position_map)
+    }
+
+    fn render_inner(
+        &mut self,
+        events: Vec<Event>,
+        mut source_map: Option<&mut Vec<SourceMapEntry>>,
+        mut position_map: Option<&mut Vec<PositionMapEntry>>,
+    ) -> fmt::Result {
+        let analysis = analyze(&events);
+        let mut broken_stack: Vec<bool> = Vec::new();
+        let mut style_stack: Vec<BreakStyle> = Vec::new();
+        let mut group_starts: Vec<(usize, GroupKind, Option<i32>)> = Vec::new();
+
+        for (i, event) in events.iter().enumerate() {
+            match event {
+                Event::GroupStart(kind, location, style) => {
+                    let (width, forced_break) = analysis.group_size(i);
+                    let broken =
+                        forced_break || self.column + width > self.config.max_line_length;
+                    broken_stack.push(broken);
+                    style_stack.push(*style);
+                    let position = self.out.len();
+                    group_starts.push((position, *kind, *location));
+                    self.annotator.on_group_start(self.out, *kind, position);
+                }
+                Event::GroupEnd => {
+                    broken_stack.pop();
+                    style_stack.pop();
+                    if let Some((start, kind, location)) = group_starts.pop() {
+                        let position = self.out.len();
+                        self.annotator.on_group_end(self.out, kind, position);
+                        if let (Some(location), Some(map)) = (location, source_map.as_deref_mut())
+                        {
+                            map.push((start..position, location, kind));
+                        }
+                    }
+                }
+                Event::Token(kind, span) => {
+                    let start = self.out.len();
+                    self.write_token(kind)?;
+                    if let (Some(span), Some(map)) = (span, position_map.as_deref_mut()) {
+                        map.push((start..self.out.len(), span.clone()));
+                    }
+                }
+                Event::ConditionalToken(kind) => {
+                    if broken_stack.last().copied().unwrap_or(false) {
+                        self.write_token(kind)?;
+                    }
+                }
+                Event::Space => {
+                    if self.pending_hard_break {
+                        self.newline()?;
+                    } else if !self.last_was_space {
+                        self.write_raw(" ")?;
+                        self.last_was_space = true;
+                    }
+                }
+                Event::Line(kind, span) => {
+                    let start = self.out.len();
+                    let broken = broken_stack.last().copied().unwrap_or(false);
+                    let style = style_stack.last().copied().unwrap_or(BreakStyle::Consistent);
+                    let should_break = *kind == LineType::Hard
+                        || (broken
+                            && match style {
+                                BreakStyle::Consistent => true,
+                                BreakStyle::Inconsistent => {
+                                    self.column + analysis.chunk_after(i)
+                                        > self.config.max_line_length
+                                }
+                            });
+                    self.write_line(*kind, should_break)?;
+                    if let (Some(span), Some(map)) = (span, position_map.as_deref_mut()) {
+                        map.push((start..self.out.len(), span.clone()));
+                    }
+                }
+                Event::IndentStart => self.indent += 1,
+                Event::IndentEnd => self.indent = self.indent.saturating_sub(1),
+                Event::Comment(comment) => self.write_comment(comment)?,
+            }
+        }
+
+        Ok(())
+    }
+
+    fn write_line(&mut self, kind: LineType, should_break: bool) -> fmt::Result {
+        if self.pending_hard_break {
+            return self.newline();
+        }
+
+        if should_break {
+            self.newline()
+        } else if kind == LineType::SoftOrSpace {
+            if self.last_was_space {
+                Ok(())
+            } else {
+                self.last_was_space = true;
+                self.write_raw(" ")
+            }
+        } else {
+            Ok(())
+        }
+    }
+
+    fn write_token(&mut self, kind: &TokenKind) -> fmt::Result {
+        self.pending_hard_break = false;
+        self.last_was_space = false;
+        let text = token_text(kind);
+        let cased = match kind {
+            TokenKind::IDENT(_) => apply_identifier_case(text, self.config.identifier_case),
+            TokenKind::LineComment(_) | TokenKind::BlockComment(_) => text,
+            _ => apply_keyword_case(text, self.config.keyword_case),
+        };
+        self.write_raw(&cased)
+    }
+
+    fn write_comment(&mut self, comment: &Comment) -> fmt::Result {
+        if comment.position == CommentPosition::Leading && self.column > 0 {
+            self.newline()?;
+        }
+        self.write_raw(&comment.text)?;
+        self.last_was_space = false;
+        if !comment.is_block {
+            // A line comment swallows everything up to the next newline, so
+            // whatever comes next must start on its own line.
+            self.pending_hard_break = true;
+        }
+        Ok(())
+    }
+
+    fn newline(&mut self) -> fmt::Result {
+        self.out.write_char('\n')?;
+        self.pending_hard_break = false;
+        self.last_was_space = true;
+        let indent_text = match self.config.indent_style {
+            IndentStyle::Spaces => " ".repeat(self.indent * self.config.indent_size),
+            IndentStyle::Tabs => "\t".repeat(self.indent),
+        };
+        self.out.write_str(&indent_text)?;
+        self.column = indent_text.len();
+        Ok(())
+    }
+
+    fn write_raw(&mut self, s: &str) -> fmt::Result {
+        self.out.write_str(s)?;
+        match s.rsplit_once('\n') {
+            Some((_, last)) => self.column = last.len(),
+            None => self.column += s.len(),
+        }
+        Ok(())
+    }
+}
+
+/// The result of [`analyze`]: every group's flat width, and every break's
+/// "next chunk" width, both keyed by the index of the `GroupStart`/`Line`
+/// event they were computed for.
+struct Analysis {
+    /// Indexed by a `GroupStart` event's index: `(flat width of the whole
+    /// group, does it contain a break that can never be collapsed)`.
+    group_width: Vec<Option<(usize, bool)>>,
+    /// Indexed by a `Line` (or `GroupStart`) event's index: the flat width of
+    /// the content from right after it to the next break or the end of its
+    /// group.
+    chunk_after: Vec<usize>,
+}
+
+impl Analysis {
+    fn group_size(&self, group_start_index: usize) -> (usize, bool) {
+        self.group_width
+            .get(group_start_index)
+            .copied()
+            .flatten()
+            .unwrap_or((0, false))
+    }
+
+    fn chunk_after(&self, line_index: usize) -> usize {
+        self.chunk_after.get(line_index).copied().unwrap_or(0)
+    }
+}
+
+/// A single forward pass over `events` computing everything [`Analysis`]
+/// needs in O(n) total (each event is visited once, and contributes to at
+/// most the handful of frames currently open on `stack`), rather than
+/// rescanning the tail of the stream once per `GroupStart` encountered while
+/// printing.
+fn analyze(events: &[Event]) -> Analysis {
+    let mut prefix = vec![0usize; events.len() + 1];
+    for (k, event) in events.iter().enumerate() {
+        prefix[k + 1] = prefix[k] + flat_width(event);
+    }
+
+    struct Frame {
+        start: usize,
+        /// Index of the last break (or this group's own `GroupStart`) whose
+        /// following chunk hasn't been closed off yet.
+        last_mark: usize,
+        forced: bool,
+    }
+
+    let mut stack: Vec<Frame> = Vec::new();
+    let mut group_width = vec![None; events.len()];
+    let mut chunk_after = vec![0usize; events.len()];
+
+    for (i, event) in events.iter().enumerate() {
+        match event {
+            Event::GroupStart(..) => {
+                stack.push(Frame {
+                    start: i,
+                    last_mark: i,
+                    forced: false,
+                });
+            }
+            Event::GroupEnd => {
+                if let Some(frame) = stack.pop() {
+                    chunk_after[frame.last_mark] = prefix[i] - prefix[frame.last_mark];
+                    let width = prefix[i] - prefix[frame.start];
+                    group_width[frame.start] = Some((width, frame.forced));
+                }
+            }
+            Event::Line(kind, _) => {
+                if *kind == LineType::Hard {
+                    // A hard break can never be collapsed, so it forces
+                    // every enclosing group - not just its own - to break
+                    // too: printing it flat inside a group still splits
+                    // that group across lines.
+                    for frame in stack.iter_mut() {
+                        frame.forced = true;
+                    }
+                }
+                if let Some(frame) = stack.last_mut() {
+                    chunk_after[frame.last_mark] = prefix[i] - prefix[frame.last_mark];
+                    frame.last_mark = i;
+                }
+            }
+            Event::Comment(comment) => {
+                // A line comment can't have anything follow it on the same
+                // line, so it always forces a break. A block comment only
+                // forces one if it spans multiple lines itself - a single-
+                // line block comment reads fine inline and can stay part of
+                // a flat group.
+                if !comment.is_block || comment.text.contains('\n') {
+                    for frame in stack.iter_mut() {
+                        frame.forced = true;
+                    }
+                }
+            }
+            Event::Token(..)
+            | Event::ConditionalToken(_)
+            | Event::Space
+            | Event::IndentStart
+            | Event::IndentEnd => {}
+        }
+    }
+
+    Analysis {
+        group_width,
+        chunk_after,
+    }
+}
+
+/// The flat-rendered width a single event contributes, for [`analyze`]'s
+/// prefix sums.
+fn flat_width(event: &Event) -> usize {
+    match event {
+        Event::Token(kind, _) => token_text(kind).len(),
+        Event::Space => 1,
+        Event::Line(LineType::SoftOrSpace, _) => 1,
+        Event::Line(LineType::Soft | LineType::Hard, _) => 0,
+        Event::Comment(comment) => comment.text.len(),
+        Event::GroupStart(..) | Event::GroupEnd | Event::IndentStart | Event::IndentEnd => 0,
+        // Weightless for the flat-width pass, same as `LineType::Soft` - it
+        // doesn't render at all unless the enclosing group breaks.
+        Event::ConditionalToken(_) => 0,
+    }
+}
+
+/// Recase keyword-class text (the `_KW` variants and [`TokenKind::KEYWORD`])
+/// per [`KeywordCase`]. Emitters always build this text in upper case, so
+/// `Upper` is a no-op.
+fn apply_keyword_case(text: Cow<'_, str>, case: KeywordCase) -> Cow<'_, str> {
+    match case {
+        KeywordCase::Preserve | KeywordCase::Upper => text,
+        KeywordCase::Lower => Cow::Owned(text.to_lowercase()),
+    }
+}
+
+/// Recase [`TokenKind::IDENT`] text per [`IdentifierCase`].
+fn apply_identifier_case(text: Cow<'_, str>, case: IdentifierCase) -> Cow<'_, str> {
+    match case {
+        IdentifierCase::Preserve => text,
+        IdentifierCase::Upper => Cow::Owned(text.to_uppercase()),
+        IdentifierCase::Lower => Cow::Owned(text.to_lowercase()),
+    }
+}
+
+fn token_text(kind: &TokenKind) -> Cow<'_, str> {
+    use TokenKind::*;
+
+    match kind {
+        IDENT(s) | LineComment(s) | BlockComment(s) | KEYWORD(s) => Cow::Borrowed(s.as_ref()),
+
+        COMMA => Cow::Borrowed(","),
+        DOT => Cow::Borrowed("."),
+        SEMICOLON => Cow::Borrowed(";"),
+        L_PAREN => Cow::Borrowed("("),
+        R_PAREN => Cow::Borrowed(")"),
+        L_BRACK => Cow::Borrowed("["),
+        R_BRACK => Cow::Borrowed("]"),
+        ADD_KW => Cow::Borrowed("ADD"),
+        AGGREGATE_KW => Cow::Borrowed("AGGREGATE"),
+        ALL_KW => Cow::Borrowed("ALL"),
+        ALTER_KW => Cow::Borrowed("ALTER"),
+        AND_KW => Cow::Borrowed("AND"),
+        ANY_KW => Cow::Borrowed("ANY"),
+        AS_KW => Cow::Borrowed("AS"),
+        ASC_KW => Cow::Borrowed("ASC"),
+        ATTRIBUTE_KW => Cow::Borrowed("ATTRIBUTE"),
+        BETWEEN_KW => Cow::Borrowed("BETWEEN"),
+        BY_KW => Cow::Borrowed("BY"),
+        CASCADED_KW => Cow::Borrowed("CASCADED"),
+        CASCADE_KW => Cow::Borrowed("CASCADE"),
+        CAST_KW => Cow::Borrowed("CAST"),
+        CHECK_KW => Cow::Borrowed("CHECK"),
+        CLASS_KW => Cow::Borrowed("CLASS"),
+        COLLATION_KW => Cow::Borrowed("COLLATION"),
+        COLUMN_KW => Cow::Borrowed("COLUMN"),
+        CONFIGURATION_KW => Cow::Borrowed("CONFIGURATION"),
+        CONSTRAINT_KW => Cow::Borrowed("CONSTRAINT"),
+        CONVERSION_KW => Cow::Borrowed("CONVERSION"),
+        CREATE_KW => Cow::Borrowed("CREATE"),
+        CROSS_KW => Cow::Borrowed("CROSS"),
+        CURRENT_KW => Cow::Borrowed("CURRENT"),
+        DATABASE_KW => Cow::Borrowed("DATABASE"),
+        DATA_KW => Cow::Borrowed("DATA"),
+        DEFAULT_KW => Cow::Borrowed("DEFAULT"),
+        DELETE_KW => Cow::Borrowed("DELETE"),
+        DESC_KW => Cow::Borrowed("DESC"),
+        DICTIONARY_KW => Cow::Borrowed("DICTIONARY"),
+        DISTINCT_KW => Cow::Borrowed("DISTINCT"),
+        DOMAIN_KW => Cow::Borrowed("DOMAIN"),
+        DO_KW => Cow::Borrowed("DO"),
+        DROP_KW => Cow::Borrowed("DROP"),
+        ESCAPE_KW => Cow::Borrowed("ESCAPE"),
+        EVENT_KW => Cow::Borrowed("EVENT"),
+        EXCEPT_KW => Cow::Borrowed("EXCEPT"),
+        EXCLUDE_KW => Cow::Borrowed("EXCLUDE"),
+        EXISTS_KW => Cow::Borrowed("EXISTS"),
+        FAMILY_KW => Cow::Borrowed("FAMILY"),
+        FIRST_KW => Cow::Borrowed("FIRST"),
+        FOLLOWING_KW => Cow::Borrowed("FOLLOWING"),
+        FOREIGN_KW => Cow::Borrowed("FOREIGN"),
+        FOR_KW => Cow::Borrowed("FOR"),
+        FROM_KW => Cow::Borrowed("FROM"),
+        FULL_KW => Cow::Borrowed("FULL"),
+        FUNCTION_KW => Cow::Borrowed("FUNCTION"),
+        GROUPS_KW => Cow::Borrowed("GROUPS"),
+        GROUP_KW => Cow::Borrowed("GROUP"),
+        HAVING_KW => Cow::Borrowed("HAVING"),
+        IF_KW => Cow::Borrowed("IF"),
+        ILIKE_KW => Cow::Borrowed("ILIKE"),
+        INDEX_KW => Cow::Borrowed("INDEX"),
+        INNER_KW => Cow::Borrowed("INNER"),
+        INSERT_KW => Cow::Borrowed("INSERT"),
+        INTERSECT_KW => Cow::Borrowed("INTERSECT"),
+        INTO_KW => Cow::Borrowed("INTO"),
+        IN_KW => Cow::Borrowed("IN"),
+        IS_KW => Cow::Borrowed("IS"),
+        JOIN_KW => Cow::Borrowed("JOIN"),
+        LANGUAGE_KW => Cow::Borrowed("LANGUAGE"),
+        LAST_KW => Cow::Borrowed("LAST"),
+        LEFT_KW => Cow::Borrowed("LEFT"),
+        LIKE_KW => Cow::Borrowed("LIKE"),
+        LIMIT_KW => Cow::Borrowed("LIMIT"),
+        LOAD_KW => Cow::Borrowed("LOAD"),
+        LOCAL_KW => Cow::Borrowed("LOCAL"),
+        MATERIALIZED_KW => Cow::Borrowed("MATERIALIZED"),
+        NATURAL_KW => Cow::Borrowed("NATURAL"),
+        NOT_KW => Cow::Borrowed("NOT"),
+        NO_KW => Cow::Borrowed("NO"),
+        NULLIF_KW => Cow::Borrowed("NULLIF"),
+        NULLS_KW => Cow::Borrowed("NULLS"),
+        OFFSET_KW => Cow::Borrowed("OFFSET"),
+        ON_KW => Cow::Borrowed("ON"),
+        OPERATOR_KW => Cow::Borrowed("OPERATOR"),
+        OPTION_KW => Cow::Borrowed("OPTION"),
+        ORDER_KW => Cow::Borrowed("ORDER"),
+        OR_KW => Cow::Borrowed("OR"),
+        OTHERS_KW => Cow::Borrowed("OTHERS"),
+        OUTER_KW => Cow::Borrowed("OUTER"),
+        PARSER_KW => Cow::Borrowed("PARSER"),
+        PARTITION_KW => Cow::Borrowed("PARTITION"),
+        POLICY_KW => Cow::Borrowed("POLICY"),
+        PRECEDING_KW => Cow::Borrowed("PRECEDING"),
+        PROCEDURE_KW => Cow::Borrowed("PROCEDURE"),
+        PUBLICATION_KW => Cow::Borrowed("PUBLICATION"),
+        RANGE_KW => Cow::Borrowed("RANGE"),
+        RENAME_KW => Cow::Borrowed("RENAME"),
+        REPLACE_KW => Cow::Borrowed("REPLACE"),
+        RESTRICT_KW => Cow::Borrowed("RESTRICT"),
+        RETURNING_KW => Cow::Borrowed("RETURNING"),
+        RIGHT_KW => Cow::Borrowed("RIGHT"),
+        ROLE_KW => Cow::Borrowed("ROLE"),
+        ROUTINE_KW => Cow::Borrowed("ROUTINE"),
+        ROWS_KW => Cow::Borrowed("ROWS"),
+        ROW_KW => Cow::Borrowed("ROW"),
+        RULE_KW => Cow::Borrowed("RULE"),
+        SCHEMA_KW => Cow::Borrowed("SCHEMA"),
+        SEARCH_KW => Cow::Borrowed("SEARCH"),
+        SELECT_KW => Cow::Borrowed("SELECT"),
+        SEQUENCE_KW => Cow::Borrowed("SEQUENCE"),
+        SERVER_KW => Cow::Borrowed("SERVER"),
+        SETOF_KW => Cow::Borrowed("SETOF"),
+        SET_KW => Cow::Borrowed("SET"),
+        SIMILAR_KW => Cow::Borrowed("SIMILAR"),
+        STATISTICS_KW => Cow::Borrowed("STATISTICS"),
+        SUBSCRIPTION_KW => Cow::Borrowed("SUBSCRIPTION"),
+        SYMMETRIC_KW => Cow::Borrowed("SYMMETRIC"),
+        TABLESPACE_KW => Cow::Borrowed("TABLESPACE"),
+        TABLE_KW => Cow::Borrowed("TABLE"),
+        TEMPLATE_KW => Cow::Borrowed("TEMPLATE"),
+        TEXT_KW => Cow::Borrowed("TEXT"),
+        TIES_KW => Cow::Borrowed("TIES"),
+        TO_KW => Cow::Borrowed("TO"),
+        TRIGGER_KW => Cow::Borrowed("TRIGGER"),
+        TRUE_KW => Cow::Borrowed("TRUE"),
+        TYPE_KW => Cow::Borrowed("TYPE"),
+        UNBOUNDED_KW => Cow::Borrowed("UNBOUNDED"),
+        UNION_KW => Cow::Borrowed("UNION"),
+        UPDATE_KW => Cow::Borrowed("UPDATE"),
+        USING_KW => Cow::Borrowed("USING"),
+        VALUES_KW => Cow::Borrowed("VALUES"),
+        VIEW_KW => Cow::Borrowed("VIEW"),
+        WHERE_KW => Cow::Borrowed("WHERE"),
+        WITHOUT_KW => Cow::Borrowed("WITHOUT"),
+        WITH_KW => Cow::Borrowed("WITH"),
+        WRAPPER_KW => Cow::Borrowed("WRAPPER"),
+    }
+}
@@ -0,0 +1,174 @@
+use std::borrow::Cow;
+
+/// The leaf tokens the emitter produces.
+///
+/// Keyword variants are named after the keyword they spell, upper-cased with
+/// a `_KW` suffix, mirroring how `pgt_query`'s own `kwlist`-derived constants
+/// are named. `IDENT` carries the literal text for identifiers, string and
+/// numeric literals, and anything else that doesn't have a fixed spelling.
+///
+/// `IDENT`/`LineComment`/`BlockComment` hold a `Cow<'static, str>` rather
+/// than a `String`. This only pays off where an emitter has a fixed,
+/// `'static` string to hand it - a literal (`"SCHEMA"`, `"SERVER"`, `"$$"`,
+/// ...) or a helper parameter like `emit_behavior`'s `on_kw` that's always
+/// called with one - which then borrows as `Cow::Borrowed` with no
+/// allocation. Text actually lifted out of the AST (identifiers, string and
+/// numeric literals) has no `'static` source to borrow from, so it still
+/// allocates into `Cow::Owned`, exactly as costly as the `String` it
+/// replaces and no costlier - this variant doesn't reduce *that* case, only
+/// the fixed-string one.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum TokenKind {
+    IDENT(Cow<'static, str>),
+
+    /// A `-- ...` line comment. Carries the comment text including the `--`
+    /// marker but excluding the trailing newline.
+    LineComment(Cow<'static, str>),
+    /// A `/* ... */` block comment, including the delimiters.
+    BlockComment(Cow<'static, str>),
+
+    /// Keyword-class text that isn't one of the fixed `_KW` variants below -
+    /// multi-word phrases (`DOUBLE PRECISION`), boolean literals (`TRUE`),
+    /// normalized type names (`BIGINT`) - carried in canonical upper case and
+    /// recased by the renderer under `RenderConfig::keyword_case`, exactly
+    /// like the `_KW` variants. Distinct from `IDENT`, which is recased
+    /// under `RenderConfig::identifier_case` instead.
+    KEYWORD(Cow<'static, str>),
+
+    COMMA,
+    DOT,
+    SEMICOLON,
+    L_PAREN,
+    R_PAREN,
+    L_BRACK,
+    R_BRACK,
+
+    ADD_KW,
+    AGGREGATE_KW,
+    ALL_KW,
+    ALTER_KW,
+    AND_KW,
+    ANY_KW,
+    AS_KW,
+    ASC_KW,
+    ATTRIBUTE_KW,
+    BETWEEN_KW,
+    BY_KW,
+    CASCADED_KW,
+    CASCADE_KW,
+    CAST_KW,
+    CHECK_KW,
+    CLASS_KW,
+    COLLATION_KW,
+    COLUMN_KW,
+    CONFIGURATION_KW,
+    CONSTRAINT_KW,
+    CONVERSION_KW,
+    CREATE_KW,
+    CROSS_KW,
+    CURRENT_KW,
+    DATABASE_KW,
+    DATA_KW,
+    DEFAULT_KW,
+    DELETE_KW,
+    DESC_KW,
+    DICTIONARY_KW,
+    DISTINCT_KW,
+    DOMAIN_KW,
+    DO_KW,
+    DROP_KW,
+    ESCAPE_KW,
+    EVENT_KW,
+    EXCEPT_KW,
+    EXCLUDE_KW,
+    EXISTS_KW,
+    FAMILY_KW,
+    FIRST_KW,
+    FOLLOWING_KW,
+    FOREIGN_KW,
+    FOR_KW,
+    FROM_KW,
+    FULL_KW,
+    FUNCTION_KW,
+    GROUPS_KW,
+    GROUP_KW,
+    HAVING_KW,
+    IF_KW,
+    ILIKE_KW,
+    INDEX_KW,
+    INNER_KW,
+    INSERT_KW,
+    INTERSECT_KW,
+    INTO_KW,
+    IN_KW,
+    IS_KW,
+    JOIN_KW,
+    LANGUAGE_KW,
+    LAST_KW,
+    LEFT_KW,
+    LIKE_KW,
+    LIMIT_KW,
+    LOAD_KW,
+    LOCAL_KW,
+    MATERIALIZED_KW,
+    NATURAL_KW,
+    NOT_KW,
+    NO_KW,
+    NULLIF_KW,
+    NULLS_KW,
+    OFFSET_KW,
+    ON_KW,
+    OPERATOR_KW,
+    OPTION_KW,
+    ORDER_KW,
+    OR_KW,
+    OTHERS_KW,
+    OUTER_KW,
+    PARSER_KW,
+    PARTITION_KW,
+    POLICY_KW,
+    PRECEDING_KW,
+    PROCEDURE_KW,
+    PUBLICATION_KW,
+    RANGE_KW,
+    RENAME_KW,
+    REPLACE_KW,
+    RESTRICT_KW,
+    RETURNING_KW,
+    RIGHT_KW,
+    ROLE_KW,
+    ROUTINE_KW,
+    ROWS_KW,
+    ROW_KW,
+    RULE_KW,
+    SCHEMA_KW,
+    SEARCH_KW,
+    SELECT_KW,
+    SEQUENCE_KW,
+    SERVER_KW,
+    SETOF_KW,
+    SET_KW,
+    SIMILAR_KW,
+    STATISTICS_KW,
+    SUBSCRIPTION_KW,
+    SYMMETRIC_KW,
+    TABLESPACE_KW,
+    TABLE_KW,
+    TEMPLATE_KW,
+    TEXT_KW,
+    TIES_KW,
+    TO_KW,
+    TRIGGER_KW,
+    TRUE_KW,
+    TYPE_KW,
+    UNBOUNDED_KW,
+    UNION_KW,
+    UPDATE_KW,
+    USING_KW,
+    VALUES_KW,
+    VIEW_KW,
+    WHERE_KW,
+    WITHOUT_KW,
+    WITH_KW,
+    WRAPPER_KW,
+}
@@ -0,0 +1,40 @@
+fn config(max_line_length: usize) -> pgt_pretty_print::renderer::RenderConfig {
+    pgt_pretty_print::renderer::RenderConfig {
+        max_line_length,
+        indent_size: 2,
+        indent_style: pgt_pretty_print::renderer::IndentStyle::Spaces,
+        keyword_case: pgt_pretty_print::renderer::KeywordCase::Upper,
+        identifier_case: pgt_pretty_print::renderer::IdentifierCase::Preserve,
+    }
+}
+
+#[test]
+fn inspect_comment_format_range() {
+    // `format_range` (the real entry point, unlike the lower-level
+    // `comments` functions `comment_round_trip.rs` drives by hand) should
+    // carry every comment through rather than silently dropping it.
+    let leading = "-- leading comment\nSELECT x, y FROM t WHERE z = 1;\n";
+    let out = pgt_pretty_print::format_range(leading, 0..leading.len(), config(80)).unwrap();
+    assert!(out.contains("-- leading comment"));
+
+    // `LoadStmt` carries no location of its own and doesn't emit any child
+    // node through `emit_node`, so `collect_locations` finds nothing to bind
+    // to here - the comment ends up in `CommentMap::orphaned` and has to be
+    // flushed as a fallback rather than silently dropped.
+    let trailing = "LOAD 'foo'; -- trailing comment\n";
+    let out = pgt_pretty_print::format_range(trailing, 0..trailing.len(), config(80)).unwrap();
+    assert!(out.contains("-- trailing comment"));
+
+    // A single-line block comment doesn't force its enclosing group to
+    // break...
+    let inline = "SELECT x FROM t WHERE y = 1 /* note */ AND z = 2;";
+    let out = pgt_pretty_print::format_range(inline, 0..inline.len(), config(80)).unwrap();
+    assert!(!out.contains('\n'), "single-line block comment shouldn't force a wrap");
+    assert!(out.contains("/* note */"));
+
+    // ...but one that spans multiple lines itself does.
+    let multiline = "SELECT x FROM t WHERE y = 1 /* multi\nline */ AND z = 2;";
+    let out = pgt_pretty_print::format_range(multiline, 0..multiline.len(), config(80)).unwrap();
+    assert!(out.contains('\n'), "multi-line block comment should force a wrap");
+    assert!(out.contains("/* multi\nline */"));
+}
@@ -0,0 +1,41 @@
+#[test]
+fn inspect_comment_round_trip() {
+    let sql = "SELECT 1 -- trailing comment\n;";
+    let parsed = pgt_query::parse(sql).unwrap();
+    let ast = parsed.into_root().unwrap();
+
+    let node_locations: Vec<i32> = match &ast {
+        pgt_query::NodeEnum::SelectStmt(stmt) => stmt
+            .target_list
+            .iter()
+            .filter_map(|n| n.node.as_ref())
+            .map(|n| match n {
+                pgt_query::NodeEnum::ResTarget(r) => r.location,
+                _ => -1,
+            })
+            .collect(),
+        _ => vec![],
+    };
+
+    let comments = pgt_pretty_print::comments::scan_comments(sql);
+    let map = pgt_pretty_print::comments::attach_comments(sql, comments, &node_locations);
+    let hook = pgt_pretty_print::comments::CommentHook::new(map);
+
+    let mut emitter = pgt_pretty_print::emitter::EventEmitter::with_hook(Box::new(hook));
+    pgt_pretty_print::nodes::emit_node_enum(&ast, &mut emitter);
+
+    let mut output = String::new();
+    let mut renderer = pgt_pretty_print::renderer::Renderer::new(
+        &mut output,
+        pgt_pretty_print::renderer::RenderConfig {
+            max_line_length: 60,
+            indent_size: 2,
+            indent_style: pgt_pretty_print::renderer::IndentStyle::Spaces,
+            keyword_case: pgt_pretty_print::renderer::KeywordCase::Upper,
+            identifier_case: pgt_pretty_print::renderer::IdentifierCase::Preserve,
+        },
+    );
+    renderer.render(emitter.events).unwrap();
+    assert!(output.contains("SELECT 1"));
+    assert!(output.contains("-- trailing comment"));
+}
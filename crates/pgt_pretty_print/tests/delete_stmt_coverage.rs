@@ -0,0 +1,35 @@
+#[test]
+fn inspect_delete_stmt_coverage() {
+    // Every statement below is already written in the canonical
+    // single-space, upper-case-keyword form the renderer produces, so each
+    // should round-trip byte-for-byte.
+    let statements = [
+        "DELETE FROM foo;",
+        "DELETE FROM foo WHERE id = 1;",
+        "DELETE FROM foo USING bar WHERE foo.id = bar.foo_id;",
+        "DELETE FROM foo USING bar, baz WHERE foo.id = bar.foo_id;",
+        "DELETE FROM foo RETURNING id, name;",
+        "DELETE FROM foo WHERE id = 1 RETURNING id;",
+        "DELETE FROM foo USING bar WHERE foo.id = bar.foo_id RETURNING foo.id, bar.name;",
+    ];
+
+    for sql in statements {
+        let parsed = pgt_query::parse(sql).unwrap();
+        let ast = parsed.into_root().unwrap();
+        let mut emitter = pgt_pretty_print::emitter::EventEmitter::new();
+        pgt_pretty_print::nodes::emit_node_enum(&ast, &mut emitter);
+        let mut output = String::new();
+        let mut renderer = pgt_pretty_print::renderer::Renderer::new(
+            &mut output,
+            pgt_pretty_print::renderer::RenderConfig {
+                max_line_length: 60,
+                indent_size: 2,
+                indent_style: pgt_pretty_print::renderer::IndentStyle::Spaces,
+                keyword_case: pgt_pretty_print::renderer::KeywordCase::Upper,
+                identifier_case: pgt_pretty_print::renderer::IdentifierCase::Preserve,
+            },
+        );
+        renderer.render(emitter.events).unwrap();
+        assert_eq!(output, sql, "expected `{sql}` to round-trip exactly");
+    }
+}
@@ -0,0 +1,24 @@
+#[test]
+fn inspect_format_range() {
+    let text = "select   1;\nselect   2;\nselect   3;\n";
+
+    // The selection only touches the middle statement; the first and last
+    // should come back byte-identical.
+    let caret = 15..16;
+
+    let config = pgt_pretty_print::renderer::RenderConfig {
+        max_line_length: 60,
+        indent_size: 2,
+        indent_style: pgt_pretty_print::renderer::IndentStyle::Spaces,
+        keyword_case: pgt_pretty_print::renderer::KeywordCase::Upper,
+        identifier_case: pgt_pretty_print::renderer::IdentifierCase::Preserve,
+    };
+
+    let formatted = pgt_pretty_print::format_range(text, caret, config).unwrap();
+
+    assert_eq!(formatted, "select   1;\nSELECT 2;\nselect   3;\n");
+    assert!(
+        !formatted.contains(";;"),
+        "must not double up the reformatted statement's trailing semicolon, got: {formatted:?}"
+    );
+}
@@ -0,0 +1,62 @@
+use pgt_pretty_print::emitter::{EventEmitter, FormatterConfig, SemicolonPolicy, SeparatorTactic};
+use pgt_pretty_print::renderer::{IdentifierCase, IndentStyle, KeywordCase, RenderConfig, Renderer};
+
+fn render(sql: &str, config: FormatterConfig, max_line_length: usize) -> String {
+    let parsed = pgt_query::parse(sql).unwrap();
+    let ast = parsed.into_root().unwrap();
+    let mut emitter = EventEmitter::with_config(config);
+    pgt_pretty_print::nodes::emit_node_enum(&ast, &mut emitter);
+    let mut output = String::new();
+    Renderer::new(
+        &mut output,
+        RenderConfig {
+            max_line_length,
+            indent_size: 2,
+            indent_style: IndentStyle::Spaces,
+            keyword_case: KeywordCase::Upper,
+            identifier_case: IdentifierCase::Preserve,
+        },
+    )
+    .render(emitter.events)
+    .unwrap();
+    output
+}
+
+#[test]
+fn inspect_formatter_config() {
+    let sql = "INSERT INTO foo (a, b, c) VALUES (1, 2, 3) RETURNING id, name, created_at;";
+
+    let default_out = render(sql, FormatterConfig::default(), 20);
+    assert!(default_out.contains(';'), "default policy always emits a semicolon");
+
+    let trailing_comma_out = render(
+        sql,
+        FormatterConfig {
+            semicolon_policy: SemicolonPolicy::Always,
+            separator_tactic: SeparatorTactic::VerticalTrailingComma,
+        },
+        20,
+    );
+    // Every wrapped list gains exactly one extra comma over the default
+    // (no-trailing-comma) rendering of the same statement at the same width.
+    let default_commas = default_out.matches(',').count();
+    let trailing_commas = trailing_comma_out.matches(',').count();
+    assert!(
+        trailing_commas > default_commas,
+        "VerticalTrailingComma should add a trailing comma once a list wraps"
+    );
+
+    let horizontal_out = render(
+        sql,
+        FormatterConfig {
+            semicolon_policy: SemicolonPolicy::Always,
+            separator_tactic: SeparatorTactic::Horizontal,
+        },
+        20,
+    );
+    // Horizontal keeps each list flat regardless of width, even though the
+    // surrounding statement is still free to wrap between clauses.
+    assert!(horizontal_out.contains("(a, b, c)"));
+    assert!(horizontal_out.contains("(1, 2, 3)"));
+    assert!(horizontal_out.contains("id, name, created_at"));
+}
@@ -0,0 +1,75 @@
+use std::cell::RefCell;
+use std::ops::Range;
+use std::rc::Rc;
+
+use pgt_pretty_print::emitter::GroupKind;
+use pgt_pretty_print::renderer::{
+    GroupAnnotator, IdentifierCase, IndentStyle, KeywordCase, RenderConfig, Renderer,
+};
+
+/// A stand-in for a folding-range provider: records every group's output
+/// span, keyed by `GroupKind`, via the spans its matching start/end calls
+/// bracket.
+struct FoldingRecorder {
+    open: Vec<(GroupKind, usize)>,
+    spans: Rc<RefCell<Vec<(GroupKind, Range<usize>)>>>,
+}
+
+impl GroupAnnotator for FoldingRecorder {
+    fn on_group_start(&mut self, _out: &mut String, kind: GroupKind, position: usize) {
+        self.open.push((kind, position));
+    }
+
+    fn on_group_end(&mut self, _out: &mut String, kind: GroupKind, position: usize) {
+        let (opened_kind, start) = self.open.pop().expect("on_group_end without a matching start");
+        assert_eq!(opened_kind, kind, "group boundaries should nest properly");
+        self.spans.borrow_mut().push((kind, start..position));
+    }
+}
+
+#[test]
+fn inspect_group_annotator() {
+    let sql = "SELECT x FROM t WHERE y = 1;";
+    let parsed = pgt_query::parse(sql).unwrap();
+    let ast = parsed.into_root().unwrap();
+
+    let mut emitter = pgt_pretty_print::emitter::EventEmitter::new();
+    pgt_pretty_print::nodes::emit_node_enum(&ast, &mut emitter);
+
+    let spans = Rc::new(RefCell::new(Vec::new()));
+    let recorder = FoldingRecorder {
+        open: Vec::new(),
+        spans: Rc::clone(&spans),
+    };
+
+    let mut output = String::new();
+    let mut renderer = Renderer::with_annotator(
+        &mut output,
+        RenderConfig {
+            max_line_length: 60,
+            indent_size: 2,
+            indent_style: IndentStyle::Spaces,
+            keyword_case: KeywordCase::Upper,
+            identifier_case: IdentifierCase::Preserve,
+        },
+        Box::new(recorder),
+    );
+    renderer.render(emitter.events).unwrap();
+    drop(renderer);
+
+    let spans = spans.borrow();
+    assert!(output.contains("SELECT x FROM t WHERE y = 1"));
+
+    // Every start/end call nested properly (checked by the `assert_eq!` in
+    // `on_group_end` above, for every group the renderer walked - not just
+    // the ones with a location `render_with_source_map` would also report).
+    assert!(
+        spans.iter().any(|(kind, _)| *kind == GroupKind::ResTarget),
+        "the SELECT target's ResTarget group should have been annotated"
+    );
+    assert!(
+        spans.iter().any(|(kind, _)| *kind == GroupKind::AExpr),
+        "the WHERE clause's AExpr group should have been annotated"
+    );
+    assert!(spans.iter().all(|(_, r)| r.start <= r.end));
+}
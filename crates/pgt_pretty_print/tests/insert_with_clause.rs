@@ -0,0 +1,50 @@
+#[test]
+fn inspect_insert_with_clause() {
+    // All of these are over the 60-column margin, so the WITH clause's CTE
+    // list wraps - each case just checks that every distinguishing piece
+    // (the MATERIALIZED hint, the INSERT target, the CTE body) still shows
+    // up, not the exact line breaks.
+    let cases: &[(&str, &[&str])] = &[
+        (
+            "WITH moved AS (DELETE FROM a RETURNING *) INSERT INTO b SELECT * FROM moved;",
+            &["WITH", "moved AS (", "DELETE FROM a RETURNING *", "INSERT INTO b", "SELECT * FROM moved"],
+        ),
+        (
+            "WITH moved AS MATERIALIZED (DELETE FROM a RETURNING *) INSERT INTO b SELECT * FROM moved;",
+            &["moved AS MATERIALIZED (", "DELETE FROM a RETURNING *", "INSERT INTO b"],
+        ),
+        (
+            "WITH moved AS NOT MATERIALIZED (DELETE FROM a RETURNING *) INSERT INTO b SELECT * FROM moved;",
+            &["moved AS NOT MATERIALIZED (", "DELETE FROM a RETURNING *", "INSERT INTO b"],
+        ),
+        (
+            "WITH RECURSIVE counted(n) AS (SELECT 1) INSERT INTO b SELECT * FROM counted;",
+            &["WITH RECURSIVE", "counted(n) AS (", "SELECT 1", "INSERT INTO b"],
+        ),
+    ];
+
+    for (sql, expected) in cases {
+        let parsed = pgt_query::parse(sql).unwrap();
+        let ast = parsed.into_root().unwrap();
+        let mut emitter = pgt_pretty_print::emitter::EventEmitter::new();
+        pgt_pretty_print::nodes::emit_node_enum(&ast, &mut emitter);
+        let mut output = String::new();
+        let mut renderer = pgt_pretty_print::renderer::Renderer::new(
+            &mut output,
+            pgt_pretty_print::renderer::RenderConfig {
+                max_line_length: 60,
+                indent_size: 2,
+                indent_style: pgt_pretty_print::renderer::IndentStyle::Spaces,
+                keyword_case: pgt_pretty_print::renderer::KeywordCase::Upper,
+                identifier_case: pgt_pretty_print::renderer::IdentifierCase::Preserve,
+            },
+        );
+        renderer.render(emitter.events).unwrap();
+        for fragment in *expected {
+            assert!(
+                output.contains(fragment),
+                "for `{sql}` expected to find `{fragment}`, got: {output}"
+            );
+        }
+    }
+}
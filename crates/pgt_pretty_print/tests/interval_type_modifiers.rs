@@ -0,0 +1,53 @@
+fn render(sql: &str) -> String {
+    let parsed = pgt_query::parse(sql).unwrap();
+    let ast = parsed.into_root().unwrap();
+    let mut emitter = pgt_pretty_print::emitter::EventEmitter::new();
+    pgt_pretty_print::nodes::emit_node_enum(&ast, &mut emitter);
+    let mut output = String::new();
+    let mut renderer = pgt_pretty_print::renderer::Renderer::new(
+        &mut output,
+        pgt_pretty_print::renderer::RenderConfig {
+            max_line_length: 60,
+            indent_size: 2,
+            indent_style: pgt_pretty_print::renderer::IndentStyle::Spaces,
+            keyword_case: pgt_pretty_print::renderer::KeywordCase::Upper,
+            identifier_case: pgt_pretty_print::renderer::IdentifierCase::Preserve,
+        },
+    );
+    renderer.render(emitter.events).unwrap();
+    output
+}
+
+#[test]
+fn inspect_interval_type_modifiers() {
+    let qualifiers = [
+        "INTERVAL",
+        "INTERVAL YEAR",
+        "INTERVAL MONTH",
+        "INTERVAL DAY",
+        "INTERVAL HOUR",
+        "INTERVAL MINUTE",
+        "INTERVAL SECOND",
+        "INTERVAL YEAR TO MONTH",
+        "INTERVAL DAY TO HOUR",
+        "INTERVAL DAY TO MINUTE",
+        "INTERVAL DAY TO SECOND",
+        "INTERVAL HOUR TO MINUTE",
+        "INTERVAL HOUR TO SECOND",
+        "INTERVAL MINUTE TO SECOND",
+        "INTERVAL SECOND(3)",
+        "INTERVAL DAY TO SECOND(3)",
+    ];
+
+    for qualifier in qualifiers {
+        let sql = format!(
+            "SELECT * FROM JSON_TABLE(data, '$' COLUMNS (c {} PATH '$.c'));",
+            qualifier
+        );
+        let output = render(&sql);
+        assert!(
+            output.contains(qualifier),
+            "expected `{qualifier}` to round-trip verbatim, got: {output}"
+        );
+    }
+}
@@ -0,0 +1,52 @@
+#[test]
+fn inspect_json_path_functions() {
+    let cases: &[(&str, &[&str])] = &[
+        ("SELECT JSON_VALUE(js, '$.a');", &["JSON_VALUE(js, '$.a')"]),
+        (
+            "SELECT JSON_VALUE(js, '$.a' RETURNING int DEFAULT 0 ON EMPTY ERROR ON ERROR);",
+            &[
+                "JSON_VALUE(js, '$.a'",
+                "RETURNING INT",
+                "DEFAULT 0 ON EMPTY",
+                "ERROR ON ERROR",
+            ],
+        ),
+        (
+            "SELECT JSON_QUERY(js, '$.a' WITH WRAPPER);",
+            &["JSON_QUERY(js, '$.a'", "WRAPPER"],
+        ),
+        (
+            "SELECT JSON_QUERY(js, '$.a' WITHOUT WRAPPER KEEP QUOTES);",
+            &["JSON_QUERY(js, '$.a'", "WITHOUT ARRAY WRAPPER", "KEEP QUOTES"],
+        ),
+        (
+            "SELECT JSON_EXISTS(js, '$.a' PASSING 1 AS x ERROR ON ERROR);",
+            &["JSON_EXISTS(js, '$.a'", "PASSING", "ERROR ON ERROR"],
+        ),
+    ];
+
+    for (sql, expected) in cases {
+        let parsed = pgt_query::parse(sql).unwrap();
+        let ast = parsed.into_root().unwrap();
+        let mut emitter = pgt_pretty_print::emitter::EventEmitter::new();
+        pgt_pretty_print::nodes::emit_node_enum(&ast, &mut emitter);
+        let mut output = String::new();
+        let mut renderer = pgt_pretty_print::renderer::Renderer::new(
+            &mut output,
+            pgt_pretty_print::renderer::RenderConfig {
+                max_line_length: 60,
+                indent_size: 2,
+                indent_style: pgt_pretty_print::renderer::IndentStyle::Spaces,
+                keyword_case: pgt_pretty_print::renderer::KeywordCase::Upper,
+                identifier_case: pgt_pretty_print::renderer::IdentifierCase::Preserve,
+            },
+        );
+        renderer.render(emitter.events).unwrap();
+        for fragment in *expected {
+            assert!(
+                output.contains(fragment),
+                "for `{sql}` expected to find `{fragment}`, got: {output}"
+            );
+        }
+    }
+}
@@ -0,0 +1,38 @@
+#[test]
+fn inspect_json_table_columns() {
+    let sql = "SELECT * FROM JSON_TABLE(data, '$.items' COLUMNS ( \
+        idx FOR ORDINALITY, \
+        name TEXT PATH '$.name', \
+        tags JSONB FORMAT JSON PATH '$.tags' WITH CONDITIONAL ARRAY WRAPPER, \
+        found JSONB EXISTS PATH '$.found' ERROR ON ERROR, \
+        price NUMERIC PATH '$.price' DEFAULT 0 ON EMPTY NULL ON ERROR, \
+        NESTED PATH '$.sub' COLUMNS ( sub_name TEXT PATH '$.name' ) \
+    ));";
+    let parsed = pgt_query::parse(sql).unwrap();
+    let ast = parsed.into_root().unwrap();
+    let mut emitter = pgt_pretty_print::emitter::EventEmitter::new();
+    pgt_pretty_print::nodes::emit_node_enum(&ast, &mut emitter);
+    let mut output = String::new();
+    let mut renderer = pgt_pretty_print::renderer::Renderer::new(
+        &mut output,
+        pgt_pretty_print::renderer::RenderConfig {
+            max_line_length: 60,
+            indent_size: 2,
+            indent_style: pgt_pretty_print::renderer::IndentStyle::Spaces,
+            keyword_case: pgt_pretty_print::renderer::KeywordCase::Upper,
+            identifier_case: pgt_pretty_print::renderer::IdentifierCase::Preserve,
+        },
+    );
+    renderer.render(emitter.events).unwrap();
+
+    assert!(output.contains("JSON_TABLE"));
+    assert!(output.contains("idx FOR ORDINALITY"));
+    assert!(output.contains("name TEXT PATH '$.name'"));
+    assert!(output.contains("FORMAT JSON"));
+    assert!(output.contains("WITH CONDITIONAL ARRAY WRAPPER"));
+    assert!(output.contains("EXISTS PATH '$.found' ERROR ON ERROR"));
+    assert!(output.contains("DEFAULT 0 ON EMPTY"));
+    assert!(output.contains("NULL ON ERROR"));
+    assert!(output.contains("NESTED PATH '$.sub'"));
+    assert!(output.contains("sub_name TEXT PATH '$.name'"));
+}
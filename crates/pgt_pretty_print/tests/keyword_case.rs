@@ -0,0 +1,51 @@
+// Every other test in this crate hardcodes `KeywordCase::Upper`, so
+// `KeywordCase::Lower` was never exercised - these cases cover it across a
+// few emitters that build keyword text as `TokenKind::KEYWORD` rather than
+// one of the fixed `_KW` variants, to make sure both paths respect it.
+fn render(sql: &str, keyword_case: pgt_pretty_print::renderer::KeywordCase) -> String {
+    let parsed = pgt_query::parse(sql).unwrap();
+    let ast = parsed.into_root().unwrap();
+    let mut emitter = pgt_pretty_print::emitter::EventEmitter::new();
+    pgt_pretty_print::nodes::emit_node_enum(&ast, &mut emitter);
+    let mut output = String::new();
+    let mut renderer = pgt_pretty_print::renderer::Renderer::new(
+        &mut output,
+        pgt_pretty_print::renderer::RenderConfig {
+            max_line_length: 80,
+            indent_size: 2,
+            indent_style: pgt_pretty_print::renderer::IndentStyle::Spaces,
+            keyword_case,
+            identifier_case: pgt_pretty_print::renderer::IdentifierCase::Preserve,
+        },
+    );
+    renderer.render(emitter.events).unwrap();
+    output
+}
+
+#[test]
+fn inspect_keyword_case() {
+    use pgt_pretty_print::renderer::KeywordCase;
+
+    // `WITH RECURSIVE` - RECURSIVE is built as `TokenKind::KEYWORD`.
+    let sql = "WITH RECURSIVE counted(n) AS (SELECT 1) SELECT * FROM counted;";
+    assert!(render(sql, KeywordCase::Upper).contains("RECURSIVE"));
+    assert!(render(sql, KeywordCase::Lower).contains("recursive"));
+
+    // `JSON_TABLE(... COLUMNS (...))` - both keywords are `TokenKind::KEYWORD`.
+    let json_table_sql = "SELECT * FROM JSON_TABLE(js, '$' COLUMNS (a int));";
+    let lower = render(json_table_sql, KeywordCase::Lower);
+    assert!(lower.contains("json_table"));
+    assert!(lower.contains("columns"));
+    assert!(!lower.contains("JSON_TABLE"));
+
+    // `CREATE SUBSCRIPTION ... CONNECTION '...'` - SUBSCRIPTION/CONNECTION are
+    // `TokenKind::KEYWORD`; the identifiers (sub name, conninfo) must stay
+    // untouched since they're cased by `IdentifierCase`, not `KeywordCase`.
+    let sub_sql = "CREATE SUBSCRIPTION Sub1 CONNECTION 'host=localhost' PUBLICATION Pub1;";
+    let sub_lower = render(sub_sql, KeywordCase::Lower);
+    assert!(sub_lower.contains("subscription"));
+    assert!(sub_lower.contains("connection"));
+    assert!(sub_lower.contains("publication"));
+    assert!(sub_lower.contains("Sub1"), "identifier casing must not follow keyword_case");
+    assert!(sub_lower.contains("Pub1"), "identifier casing must not follow keyword_case");
+}
@@ -0,0 +1,53 @@
+fn format(sql: &str, max_line_length: usize) -> String {
+    let parsed = pgt_query::parse(sql).unwrap();
+    let ast = parsed.into_root().unwrap();
+    let mut emitter = pgt_pretty_print::emitter::EventEmitter::new();
+    pgt_pretty_print::nodes::emit_node_enum(&ast, &mut emitter);
+    let mut output = String::new();
+    let mut renderer = pgt_pretty_print::renderer::Renderer::new(
+        &mut output,
+        pgt_pretty_print::renderer::RenderConfig {
+            max_line_length,
+            indent_size: 2,
+            indent_style: pgt_pretty_print::renderer::IndentStyle::Spaces,
+            keyword_case: pgt_pretty_print::renderer::KeywordCase::Upper,
+            identifier_case: pgt_pretty_print::renderer::IdentifierCase::Preserve,
+        },
+    );
+    renderer.render(emitter.events).unwrap();
+    output
+}
+
+#[test]
+fn inspect_line_wrapping() {
+    let short = format("SELECT a IN (1, 2, 3);", 80);
+    assert!(!short.contains('\n'), "short IN list should stay flat");
+    assert!(short.contains("IN (1, 2, 3)"));
+
+    let long = format(
+        "SELECT a IN (1111111, 2222222, 3333333, 4444444, 5555555, 6666666);",
+        30,
+    );
+    assert!(long.contains('\n'), "long IN list should wrap");
+    // Every item still appears exactly once, just spread across lines.
+    for item in ["1111111", "2222222", "3333333", "4444444", "5555555", "6666666"] {
+        assert!(long.contains(item));
+    }
+
+    // BETWEEN ... AND and nested parens aren't comma lists, so they're
+    // unaffected by the new list-wrapping group and stay flat either way.
+    let between = format("SELECT a BETWEEN 1 AND 2;", 80);
+    assert!(!between.contains('\n'));
+    assert!(between.contains("BETWEEN 1 AND 2"));
+
+    for sql in [
+        "CREATE SUBSCRIPTION sub CONNECTION 'host=localhost' PUBLICATION pub1, pub2;",
+        "ALTER SUBSCRIPTION sub SET (synchronous_commit = 'off', binary = true);",
+    ] {
+        let wide = format(sql, 80);
+        assert!(!wide.contains('\n'));
+
+        let narrow = format(sql, 20);
+        assert!(narrow.contains('\n'), "narrow margin should force a wrap");
+    }
+}
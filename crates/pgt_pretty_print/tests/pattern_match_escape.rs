@@ -0,0 +1,34 @@
+#[test]
+fn inspect_pattern_match_escape() {
+    // Every statement here is already in the canonical single-space,
+    // upper-case-keyword form the renderer produces, so each round-trips
+    // byte-for-byte.
+    let statements = [
+        "SELECT x WHERE x LIKE '%a' ESCAPE '!';",
+        "SELECT x WHERE x NOT LIKE '%a' ESCAPE '!';",
+        "SELECT x WHERE x ILIKE '%a' ESCAPE '!';",
+        "SELECT x WHERE x SIMILAR TO '%a' ESCAPE '!';",
+        "SELECT x WHERE x NOT SIMILAR TO '%a' ESCAPE '!';",
+        "SELECT x WHERE x LIKE '%a';",
+    ];
+
+    for sql in statements {
+        let parsed = pgt_query::parse(sql).unwrap();
+        let ast = parsed.into_root().unwrap();
+        let mut emitter = pgt_pretty_print::emitter::EventEmitter::new();
+        pgt_pretty_print::nodes::emit_node_enum(&ast, &mut emitter);
+        let mut output = String::new();
+        let mut renderer = pgt_pretty_print::renderer::Renderer::new(
+            &mut output,
+            pgt_pretty_print::renderer::RenderConfig {
+                max_line_length: 60,
+                indent_size: 2,
+                indent_style: pgt_pretty_print::renderer::IndentStyle::Spaces,
+                keyword_case: pgt_pretty_print::renderer::KeywordCase::Upper,
+                identifier_case: pgt_pretty_print::renderer::IdentifierCase::Preserve,
+            },
+        );
+        renderer.render(emitter.events).unwrap();
+        assert_eq!(output, sql, "expected `{sql}` to round-trip exactly");
+    }
+}
@@ -0,0 +1,56 @@
+#[test]
+fn inspect_precedence_parens() {
+    // Each pair is (source, the grouping it must still show after a
+    // round-trip). `pgt_query` drops parentheses that don't change meaning
+    // from its AST, so the formatter has to re-derive which ones matter from
+    // operator precedence - see `nodes::precedence`.
+    let cases = [
+        ("SELECT (a OR b) AND c;", "(a OR b) AND c"),
+        ("SELECT NOT (a AND b);", "NOT (a AND b)"),
+        ("SELECT a - (b - c);", "a - (b - c)"),
+        ("SELECT a * (b + c);", "a * (b + c)"),
+        ("SELECT a OR b AND c;", "a OR b AND c"),
+        ("SELECT (a + b) * c;", "(a + b) * c"),
+        // `^` is left-associative (`%left '^'` in Postgres' grammar), so a
+        // left-deep chain stays flat but a right-grouped one needs parens
+        // re-added to keep the same value on round-trip.
+        ("SELECT 2 ^ 3 ^ 2;", "2 ^ 3 ^ 2"),
+        ("SELECT 2 ^ (3 ^ 2);", "2 ^ (3 ^ 2)"),
+        // Unary minus binds tighter than `^`, so wrapping it as the base of
+        // an exponentiation needs no parens, but applying it to an already
+        // exponentiated value does.
+        ("SELECT (-x) ^ 2;", "-x ^ 2"),
+        ("SELECT -(x ^ 2);", "-(x ^ 2)"),
+    ];
+
+    for (sql, expected) in cases {
+        let parsed = pgt_query::parse(sql).unwrap();
+        let ast = parsed.into_root().unwrap();
+        let mut emitter = pgt_pretty_print::emitter::EventEmitter::new();
+        pgt_pretty_print::nodes::emit_node_enum(&ast, &mut emitter);
+        let mut output = String::new();
+        let mut renderer = pgt_pretty_print::renderer::Renderer::new(
+            &mut output,
+            pgt_pretty_print::renderer::RenderConfig {
+                max_line_length: 60,
+                indent_size: 2,
+                indent_style: pgt_pretty_print::renderer::IndentStyle::Spaces,
+                keyword_case: pgt_pretty_print::renderer::KeywordCase::Upper,
+                identifier_case: pgt_pretty_print::renderer::IdentifierCase::Preserve,
+            },
+        );
+        renderer.render(emitter.events).unwrap();
+        assert!(
+            output.contains(expected),
+            "for `{sql}` expected to find `{expected}`, got: {output}"
+        );
+        if !expected.contains('(') {
+            // Precedence that's already unambiguous shouldn't grow
+            // parentheses that weren't in the original grouping.
+            assert!(
+                !output.contains('('),
+                "for `{sql}` expected no added parentheses, got: {output}"
+            );
+        }
+    }
+}
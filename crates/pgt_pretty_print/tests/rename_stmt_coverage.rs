@@ -0,0 +1,71 @@
+#[test]
+fn inspect_rename_stmt_coverage() {
+    // Most of these round-trip byte-for-byte - they're already written in
+    // the canonical single-space, upper-case-keyword form the renderer
+    // produces. `ALTER GROUP`/`ALTER USER` are the exception: Postgres
+    // normalizes both to `OBJECT_ROLE` in the parsed tree with no way to
+    // recover which spelling was used, so they come back as `ALTER ROLE`.
+    let statements = [
+        "ALTER TABLE foo RENAME TO bar;",
+        "ALTER TABLE foo RENAME COLUMN a TO b;",
+        "ALTER TABLE foo RENAME CONSTRAINT a TO b;",
+        "ALTER VIEW foo RENAME TO bar;",
+        "ALTER MATERIALIZED VIEW foo RENAME TO bar;",
+        "ALTER INDEX foo RENAME TO bar;",
+        "ALTER SEQUENCE foo RENAME TO bar;",
+        "ALTER DOMAIN foo RENAME TO bar;",
+        "ALTER DOMAIN foo RENAME CONSTRAINT a TO b;",
+        "ALTER COLLATION foo RENAME TO bar;",
+        "ALTER CONVERSION foo RENAME TO bar;",
+        "ALTER STATISTICS foo RENAME TO bar;",
+        "ALTER TEXT SEARCH PARSER foo RENAME TO bar;",
+        "ALTER TEXT SEARCH DICTIONARY foo RENAME TO bar;",
+        "ALTER TEXT SEARCH TEMPLATE foo RENAME TO bar;",
+        "ALTER TEXT SEARCH CONFIGURATION foo RENAME TO bar;",
+        "ALTER PUBLICATION foo RENAME TO bar;",
+        "ALTER SUBSCRIPTION foo RENAME TO bar;",
+        "ALTER EVENT TRIGGER foo RENAME TO bar;",
+        "ALTER FOREIGN DATA WRAPPER foo RENAME TO bar;",
+        "ALTER SERVER foo RENAME TO bar;",
+        "ALTER TABLESPACE foo RENAME TO bar;",
+        "ALTER ROLE foo RENAME TO bar;",
+        "ALTER POLICY foo ON tbl RENAME TO bar;",
+        "ALTER RULE foo ON tbl RENAME TO bar;",
+        "ALTER TRIGGER foo ON tbl RENAME TO bar;",
+        "ALTER TYPE foo RENAME ATTRIBUTE a TO b CASCADE;",
+        "ALTER TYPE foo RENAME TO bar;",
+        "ALTER FUNCTION foo() RENAME TO bar;",
+        "ALTER PROCEDURE foo() RENAME TO bar;",
+        "ALTER ROUTINE foo() RENAME TO bar;",
+        "ALTER LANGUAGE foo RENAME TO bar;",
+        "ALTER OPERATOR CLASS foo USING btree RENAME TO bar;",
+        "ALTER OPERATOR FAMILY foo USING btree RENAME TO bar;",
+    ];
+
+    for sql in statements {
+        assert_eq!(render(sql), sql, "expected `{sql}` to round-trip exactly");
+    }
+
+    assert_eq!(render("ALTER GROUP foo RENAME TO bar;"), "ALTER ROLE foo RENAME TO bar;");
+    assert_eq!(render("ALTER USER foo RENAME TO bar;"), "ALTER ROLE foo RENAME TO bar;");
+}
+
+fn render(sql: &str) -> String {
+    let parsed = pgt_query::parse(sql).unwrap();
+    let ast = parsed.into_root().unwrap();
+    let mut emitter = pgt_pretty_print::emitter::EventEmitter::new();
+    pgt_pretty_print::nodes::emit_node_enum(&ast, &mut emitter);
+    let mut output = String::new();
+    let mut renderer = pgt_pretty_print::renderer::Renderer::new(
+        &mut output,
+        pgt_pretty_print::renderer::RenderConfig {
+            max_line_length: 60,
+            indent_size: 2,
+            indent_style: pgt_pretty_print::renderer::IndentStyle::Spaces,
+            keyword_case: pgt_pretty_print::renderer::KeywordCase::Upper,
+            identifier_case: pgt_pretty_print::renderer::IdentifierCase::Preserve,
+        },
+    );
+    renderer.render(emitter.events).unwrap();
+    output
+}
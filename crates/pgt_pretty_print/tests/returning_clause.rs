@@ -0,0 +1,43 @@
+#[test]
+fn inspect_returning_clause() {
+    // `RETURNING` is shared by INSERT and DELETE (and UPDATE) via
+    // `res_target::emit_returning_list` - each case just needs the clause
+    // itself to show up verbatim, regardless of how the narrow 40-column
+    // margin wraps the rest of the statement.
+    let cases = [
+        ("INSERT INTO foo (a, b) VALUES (1, 2) RETURNING id;", "RETURNING id"),
+        (
+            "INSERT INTO foo (a, b) VALUES (1, 2) RETURNING id, name;",
+            "RETURNING id, name",
+        ),
+        (
+            "INSERT INTO foo (a) VALUES (1) ON CONFLICT (a) DO NOTHING RETURNING id;",
+            "RETURNING id",
+        ),
+        ("DELETE FROM foo RETURNING id, name;", "RETURNING id, name"),
+    ];
+
+    for (sql, expected) in cases {
+        let parsed = pgt_query::parse(sql).unwrap();
+        let ast = parsed.into_root().unwrap();
+        let mut emitter = pgt_pretty_print::emitter::EventEmitter::new();
+        pgt_pretty_print::nodes::emit_node_enum(&ast, &mut emitter);
+        let mut output = String::new();
+        let mut renderer = pgt_pretty_print::renderer::Renderer::new(
+            &mut output,
+            pgt_pretty_print::renderer::RenderConfig {
+                max_line_length: 40,
+                indent_size: 2,
+                indent_style: pgt_pretty_print::renderer::IndentStyle::Spaces,
+                keyword_case: pgt_pretty_print::renderer::KeywordCase::Upper,
+                identifier_case: pgt_pretty_print::renderer::IdentifierCase::Preserve,
+            },
+        );
+        renderer.render(emitter.events).unwrap();
+        assert!(
+            output.contains(expected),
+            "for `{sql}` expected to find `{expected}`, got: {output}"
+        );
+        assert!(output.ends_with(';'));
+    }
+}
@@ -0,0 +1,48 @@
+fn config() -> pgt_pretty_print::renderer::RenderConfig {
+    pgt_pretty_print::renderer::RenderConfig {
+        max_line_length: 60,
+        indent_size: 2,
+        indent_style: pgt_pretty_print::renderer::IndentStyle::Spaces,
+        keyword_case: pgt_pretty_print::renderer::KeywordCase::Upper,
+        identifier_case: pgt_pretty_print::renderer::IdentifierCase::Preserve,
+    }
+}
+
+#[test]
+fn inspect_res_target_and_a_expr_spans() {
+    let sql = "UPDATE t SET x = a + b WHERE y = 1;";
+    let parsed = pgt_query::parse(sql).unwrap();
+    let ast = parsed.into_root().unwrap();
+
+    let mut emitter = pgt_pretty_print::emitter::EventEmitter::new();
+    pgt_pretty_print::nodes::emit_node_enum(&ast, &mut emitter);
+
+    let mut output = String::new();
+    let mut renderer = pgt_pretty_print::renderer::Renderer::new(&mut output, config());
+    let source_map = renderer.render_with_source_map(emitter.events).unwrap();
+    assert!(output.contains("UPDATE t SET x = a + b WHERE y = 1"));
+
+    let res_targets: Vec<_> = source_map
+        .iter()
+        .filter(|(_, _, kind)| matches!(kind, pgt_pretty_print::emitter::GroupKind::ResTarget))
+        .collect();
+    assert!(
+        !res_targets.is_empty(),
+        "SET x = ... should carry a ResTarget span"
+    );
+
+    let a_exprs: Vec<_> = source_map
+        .iter()
+        .filter(|(_, _, kind)| matches!(kind, pgt_pretty_print::emitter::GroupKind::AExpr))
+        .collect();
+    assert!(!a_exprs.is_empty(), "a + b should carry an AExpr span");
+
+    // Every span's `location` should point back somewhere inside the
+    // original statement text.
+    for (range, location, _) in &source_map {
+        assert!(
+            (*location as usize) < sql.len(),
+            "location {location} out of bounds for range {range:?}"
+        );
+    }
+}
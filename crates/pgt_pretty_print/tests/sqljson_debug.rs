@@ -25,6 +25,8 @@ fn debug_sqljson_first_difference() {
                 max_line_length: 60,
                 indent_size: 2,
                 indent_style: pgt_pretty_print::renderer::IndentStyle::Spaces,
+                keyword_case: pgt_pretty_print::renderer::KeywordCase::Upper,
+                identifier_case: pgt_pretty_print::renderer::IdentifierCase::Preserve,
             },
         );
         renderer.render(emitter.events).unwrap();
@@ -0,0 +1,77 @@
+#[test]
+fn inspect_subscription_coverage() {
+    // Most statements round-trip exactly. `OPTIONS`-style `name = value`
+    // pairs are the exception: pgt_query's `DefElem` doesn't carry the `=`,
+    // so `emit_options_def_elem` (unlike `SKIP`'s `emit_skip_def_elem`,
+    // which does keep it) renders `name value` instead, and boolean values
+    // come back as the `TRUE`/`FALSE` keywords.
+    let cases = [
+        (
+            "CREATE SUBSCRIPTION sub CONNECTION 'host=localhost' PUBLICATION pub1, pub2;",
+            "CREATE SUBSCRIPTION sub CONNECTION 'host=localhost' PUBLICATION pub1, pub2;",
+        ),
+        (
+            "CREATE SUBSCRIPTION sub CONNECTION 'host=localhost' PUBLICATION pub1 WITH (enabled = false);",
+            "CREATE SUBSCRIPTION sub CONNECTION 'host=localhost' PUBLICATION pub1 WITH (enabled FALSE);",
+        ),
+        ("CREATE SUBSCRIPTION sub;", "CREATE SUBSCRIPTION sub;"),
+        (
+            "ALTER SUBSCRIPTION sub SET (synchronous_commit = 'off');",
+            "ALTER SUBSCRIPTION sub SET (synchronous_commit 'off');",
+        ),
+        (
+            "ALTER SUBSCRIPTION sub CONNECTION 'host=localhost';",
+            "ALTER SUBSCRIPTION sub CONNECTION 'host=localhost';",
+        ),
+        (
+            "ALTER SUBSCRIPTION sub SET PUBLICATION pub1, pub2;",
+            "ALTER SUBSCRIPTION sub SET PUBLICATION pub1, pub2;",
+        ),
+        (
+            "ALTER SUBSCRIPTION sub SET PUBLICATION pub1 WITH (refresh = false);",
+            "ALTER SUBSCRIPTION sub SET PUBLICATION pub1 WITH (refresh FALSE);",
+        ),
+        (
+            "ALTER SUBSCRIPTION sub ADD PUBLICATION pub2;",
+            "ALTER SUBSCRIPTION sub ADD PUBLICATION pub2;",
+        ),
+        (
+            "ALTER SUBSCRIPTION sub DROP PUBLICATION pub2;",
+            "ALTER SUBSCRIPTION sub DROP PUBLICATION pub2;",
+        ),
+        (
+            "ALTER SUBSCRIPTION sub REFRESH PUBLICATION;",
+            "ALTER SUBSCRIPTION sub REFRESH PUBLICATION;",
+        ),
+        (
+            "ALTER SUBSCRIPTION sub REFRESH PUBLICATION WITH (copy_data = false);",
+            "ALTER SUBSCRIPTION sub REFRESH PUBLICATION WITH (copy_data FALSE);",
+        ),
+        ("ALTER SUBSCRIPTION sub ENABLE;", "ALTER SUBSCRIPTION sub ENABLE;"),
+        ("ALTER SUBSCRIPTION sub DISABLE;", "ALTER SUBSCRIPTION sub DISABLE;"),
+        (
+            "ALTER SUBSCRIPTION sub SKIP (lsn = '0/3FB5A90');",
+            "ALTER SUBSCRIPTION sub SKIP (lsn = '0/3FB5A90');",
+        ),
+    ];
+
+    for (sql, expected) in cases {
+        let parsed = pgt_query::parse(sql).unwrap();
+        let ast = parsed.into_root().unwrap();
+        let mut emitter = pgt_pretty_print::emitter::EventEmitter::new();
+        pgt_pretty_print::nodes::emit_node_enum(&ast, &mut emitter);
+        let mut output = String::new();
+        let mut renderer = pgt_pretty_print::renderer::Renderer::new(
+            &mut output,
+            pgt_pretty_print::renderer::RenderConfig {
+                max_line_length: 60,
+                indent_size: 2,
+                indent_style: pgt_pretty_print::renderer::IndentStyle::Spaces,
+                keyword_case: pgt_pretty_print::renderer::KeywordCase::Upper,
+                identifier_case: pgt_pretty_print::renderer::IdentifierCase::Preserve,
+            },
+        );
+        renderer.render(emitter.events).unwrap();
+        assert_eq!(output, expected, "while formatting `{sql}`");
+    }
+}
@@ -0,0 +1,54 @@
+fn format(sql: &str) -> String {
+    let parsed = pgt_query::parse(sql).unwrap();
+    let ast = parsed.into_root().unwrap();
+    let mut emitter = pgt_pretty_print::emitter::EventEmitter::new();
+    pgt_pretty_print::nodes::emit_node_enum(&ast, &mut emitter);
+    let mut output = String::new();
+    pgt_pretty_print::renderer::Renderer::new(
+        &mut output,
+        pgt_pretty_print::renderer::RenderConfig {
+            max_line_length: 60,
+            indent_size: 2,
+            indent_style: pgt_pretty_print::renderer::IndentStyle::Spaces,
+            keyword_case: pgt_pretty_print::renderer::KeywordCase::Upper,
+            identifier_case: pgt_pretty_print::renderer::IdentifierCase::Preserve,
+        },
+    )
+    .render(emitter.events)
+    .unwrap();
+    output
+}
+
+#[test]
+fn inspect_token_spacing() {
+    let statements = [
+        "DO LANGUAGE plpgsql $$ begin end $$;",
+        "SELECT JSON('{}');",
+        "SELECT ROW(1, 2);",
+        "SELECT NULLIF(a, b);",
+    ];
+
+    for sql in statements {
+        let output = format(sql);
+
+        // The emitter's automatic token-adjacency spacing and any manual
+        // `e.space()` a node emitter still writes by hand must agree, not
+        // stack: neither a missing space nor a doubled one should appear.
+        assert!(
+            !output.contains("  "),
+            "doubled-up space in output: {output}"
+        );
+    }
+
+    let do_block = format("DO LANGUAGE plpgsql $$ begin end $$;");
+    assert!(
+        do_block.contains("$$ begin end $$"),
+        "dollar-quoted body must not get a space spliced in around its delimiters: {do_block}"
+    );
+
+    let json_call = format("SELECT JSON('{}');");
+    assert!(
+        json_call.contains("JSON('"),
+        "JSON(...) is a function-call-style keyword and shouldn't get a space before its `(`: {json_call}"
+    );
+}
@@ -0,0 +1,44 @@
+fn format(sql: &str) -> String {
+    let parsed = pgt_query::parse(sql).unwrap();
+    let ast = parsed.into_root().unwrap();
+    let mut emitter = pgt_pretty_print::emitter::EventEmitter::new();
+    pgt_pretty_print::nodes::emit_node_enum(&ast, &mut emitter);
+    let mut output = String::new();
+    let mut renderer = pgt_pretty_print::renderer::Renderer::new(
+        &mut output,
+        pgt_pretty_print::renderer::RenderConfig {
+            max_line_length: 20,
+            indent_size: 2,
+            indent_style: pgt_pretty_print::renderer::IndentStyle::Spaces,
+            keyword_case: pgt_pretty_print::renderer::KeywordCase::Upper,
+            identifier_case: pgt_pretty_print::renderer::IdentifierCase::Preserve,
+        },
+    );
+    renderer.render(emitter.events).unwrap();
+    output
+}
+
+#[test]
+fn inspect_values_row_grouping() {
+    let single = format("INSERT INTO foo (a, b) VALUES (1, 2);");
+    assert!(single.contains("VALUES"));
+    assert!(single.contains("(1, 2)"));
+
+    // At a 20-column margin the multi-row VALUES list can't stay flat, so it
+    // breaks - but each row is its own `GroupKind::ValuesRow` group, so a
+    // row's own tuple must stay together rather than breaking mid-row.
+    let multi = format("INSERT INTO foo (a, b) VALUES (1, 2), (3, 4), (5, 6);");
+    assert!(multi.contains('\n'), "multi-row VALUES should wrap at a 20-column margin");
+    for row in ["(1, 2)", "(3, 4)", "(5, 6)"] {
+        assert!(multi.contains(row), "row {row} should stay intact, got: {multi}");
+    }
+
+    let bare_values = format("VALUES (1, 2, 3), (4, 5, 6);");
+    assert!(bare_values.contains('\n'));
+    for row in ["(1, 2, 3)", "(4, 5, 6)"] {
+        assert!(
+            bare_values.contains(row),
+            "row {row} should stay intact, got: {bare_values}"
+        );
+    }
+}